@@ -0,0 +1,145 @@
+/// The answer to one part of a day's puzzle.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Answer {
+    /// Wide enough to hold any signed or unsigned 64-bit answer without loss.
+    Integer(i128),
+    String(String),
+    /// A picture, one string per row.
+    Grid(Vec<String>),
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => n.fmt(f),
+            Answer::String(s) => s.fmt(f),
+            Answer::Grid(rows) => {
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    f.write_str(row)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+/// Answers serialize as a plain JSON number, string, or array of rows.
+impl serde::Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(n) => serializer.serialize_i128(*n),
+            Answer::String(s) => serializer.serialize_str(s),
+            Answer::Grid(rows) => serde::Serialize::serialize(rows, serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Answer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> serde::de::Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("an integer, a string, or a list of rows")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, n: i64) -> Result<Answer, E> {
+        Ok(n.into())
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, n: u64) -> Result<Answer, E> {
+        Ok(n.into())
+    }
+
+    fn visit_i128<E: serde::de::Error>(self, n: i128) -> Result<Answer, E> {
+        Ok(Answer::Integer(n))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Answer, E> {
+        Ok(s.into())
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Answer, A::Error> {
+        let mut rows = Vec::new();
+        while let Some(row) = seq.next_element()? {
+            rows.push(row);
+        }
+        Ok(Answer::Grid(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-12_i64).to_string(), "-12");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(
+            Answer::from(vec!["#.".to_string(), ".#".to_string()]).to_string(),
+            "#.\n.#"
+        );
+    }
+
+    #[test]
+    fn integers_compare_regardless_of_source_type() {
+        assert_eq!(Answer::from(42_usize), Answer::from(42_i64));
+        assert_ne!(Answer::from(42_u32), Answer::from("42"));
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for (answer, json) in &[
+            (Answer::from(13891280_u64), "13891280"),
+            (Answer::from(-3_i64), "-3"),
+            (Answer::from("bxjvzk,hqgqj"), r#""bxjvzk,hqgqj""#),
+            (Answer::from(vec!["#.".to_string()]), r##"["#."]"##),
+        ] {
+            assert_eq!(serde_json::to_string(answer).unwrap(), *json);
+            assert_eq!(&serde_json::from_str::<Answer>(json).unwrap(), answer);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{Result, Solution};
+use serde::ser::SerializeStruct;
+use std::time::{Duration, Instant};
+
+/// How long parsing and each part took, over a number of runs.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct Timings {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    /// Timings from a single run.
+    pub fn once(parse: Duration, part1: Duration, part2: Duration) -> Timings {
+        Timings {
+            runs: 1,
+            parse: Stats::from_samples(&mut [parse]),
+            part1: Stats::from_samples(&mut [part1]),
+            part2: Stats::from_samples(&mut [part2]),
+        }
+    }
+}
+
+/// The spread of a set of timings.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Can't summarize zero samples");
+        samples.sort();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[len - 1],
+        }
+    }
+}
+
+/// Timings serialize as whole nanoseconds, so they can be compared without parsing units.
+impl serde::Serialize for Stats {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Stats", 3)?;
+        s.serialize_field("min_ns", &self.min.as_nanos())?;
+        s.serialize_field("median_ns", &self.median.as_nanos())?;
+        s.serialize_field("max_ns", &self.max.as_nanos())?;
+        s.end()
+    }
+}
+
+/// Parse the input and solve both parts `runs` times, timing each step separately.
+pub fn bench<'a, S: Solution<'a>>(input: &'a str, runs: usize) -> Result<Timings> {
+    if runs == 0 {
+        return Err("Must run at least once".into());
+    }
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let solution = S::parse(input)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        solution.part1()?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        solution.part2()?;
+        part2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        runs,
+        parse: Stats::from_samples(&mut parse),
+        part1: Stats::from_samples(&mut part1),
+        part2: Stats::from_samples(&mut part2),
+    })
+}
+
+/// Print the header of a table of `time_runs` rows, naming what the sizes and labels are.
+pub fn print_header(size: &str, label: &str) {
+    println!(
+        "{:>9}  {:<14} {:>10} {:>10} {:>10}",
+        size, label, "min", "median", "max"
+    );
+}
+
+/// Run `f` `runs` times on an input of `size`, print a row of the table with how long it took,
+/// and return what it returned the last time.
+pub fn time_runs<T>(size: usize, label: &str, runs: usize, mut f: impl FnMut() -> T) -> T {
+    assert!(runs > 0, "Must run at least once");
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+
+    let stats = Stats::from_samples(&mut samples);
+    println!(
+        "{:>9}  {:<14} {:>10} {:>10} {:>10}",
+        size,
+        label,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.max)
+    );
+    result.unwrap()
+}
+
+/// A small, seeded pseudo-random number generator, so generated bench inputs are the same from
+/// run to run.
+#[derive(Debug, Clone)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        Lcg(seed)
+    }
+
+    /// A number from `0` up to but not including `n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+}
+
+/// A short, human-readable duration such as `12.3ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&mut [ms(5), ms(1), ms(3)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+
+        let stats = Stats::from_samples(&mut [ms(8), ms(2), ms(4), ms(1)]);
+        assert_eq!(stats.median, ms(3));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_millis(2345)), "2.35s");
+    }
+
+    #[test]
+    fn lcg() {
+        let mut a = Lcg::new(0x2020);
+        let mut b = Lcg::new(0x2020);
+        let numbers: Vec<u64> = (0..100).map(|_| a.below(10)).collect();
+        assert!(numbers.iter().all(|&n| n < 10));
+        assert_eq!(numbers, (0..100).map(|_| b.below(10)).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,247 @@
+//! Command-line handling shared by every day's binary.
+//!
+//! Every day takes `--input PATH` to read its puzzle input from a file, or `-` (or
+//! `--input -`) to read it from stdin. Without either, it uses the `input.txt` embedded in the
+//! binary, for the days that have one. Days can also take their own named flags, such as
+//! `--preamble 25`, and switches, such as `--stream`.
+//!
+//! `--format json` prints the answers as JSON instead of plain text.
+
+use crate::Result;
+use std::collections::BTreeMap;
+use std::io::{BufRead, Read};
+
+/// A day-specific flag: its name without the leading dashes, and a placeholder for its value. A
+/// flag with an empty placeholder is a switch, which doesn't take a value.
+pub type Flag = (&'static str, &'static str);
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Cli {
+    input: Option<String>,
+    format: Format,
+    flags: BTreeMap<&'static str, String>,
+}
+
+/// How a day prints its answers.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format '{}', expected text or json", s)),
+        }
+    }
+}
+
+impl Cli {
+    /// Parse the process's arguments, accepting `--input` and the given flags. Prints the usage
+    /// and exits on bad arguments or `--help`.
+    pub fn parse(flags: &[Flag]) -> Cli {
+        let mut args = std::env::args();
+        let name = args.next().unwrap_or_default();
+        let name = std::path::Path::new(&name)
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        let args: Vec<String> = args.collect();
+
+        if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+            print!("{}", usage(&name, flags));
+            std::process::exit(0);
+        }
+        Cli::from_args(&args, flags).unwrap_or_else(|err| {
+            eprint!("error: {}\n\n{}", err, usage(&name, flags));
+            std::process::exit(2)
+        })
+    }
+
+    pub fn from_args(args: &[String], flags: &[Flag]) -> Result<Cli> {
+        let mut cli = Cli::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "-" {
+                cli.set_input("-")?;
+                continue;
+            }
+            let flag = arg
+                .strip_prefix("--")
+                .ok_or_else(|| format!("Unexpected argument '{}'", arg))?;
+            let (name, value) = match flag.find('=') {
+                Some(equals) => (&flag[..equals], Some(flag[(equals + 1)..].to_string())),
+                None => (flag, None),
+            };
+            let is_switch = flags.contains(&(name, ""));
+            let value = match value {
+                Some(_) if is_switch => {
+                    return Err(format!("--{} doesn't take a value", name).into())
+                }
+                Some(value) => value,
+                None if is_switch => String::new(),
+                None => args
+                    .next()
+                    .cloned()
+                    .ok_or_else(|| format!("--{} requires a value", name))?,
+            };
+
+            if name == "input" {
+                cli.set_input(&value)?;
+            } else if name == "format" {
+                cli.format = value.parse()?;
+            } else if let Some((name, _)) = flags.iter().find(|(flag, _)| *flag == name) {
+                if cli.flags.insert(name, value).is_some() {
+                    return Err(format!("--{} given more than once", name).into());
+                }
+            } else {
+                return Err(format!("Unknown flag '--{}'", name).into());
+            }
+        }
+
+        Ok(cli)
+    }
+
+    fn set_input(&mut self, input: &str) -> Result<()> {
+        if self.input.is_some() {
+            return Err("Only one input can be given".into());
+        }
+        self.input = Some(input.to_string());
+        Ok(())
+    }
+
+    /// The value of a day-specific flag, if it was given.
+    pub fn flag(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).map(String::as_str)
+    }
+
+    /// Whether a day-specific switch was given.
+    pub fn switch(&self, name: &str) -> bool {
+        self.flags.contains_key(name)
+    }
+
+    /// The parsed value of a day-specific flag, if it was given.
+    pub fn parse_flag<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        match self.flag(name) {
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|err| format!("Invalid --{} '{}': {}", name, value, err).into()),
+            None => Ok(None),
+        }
+    }
+
+    /// The parsed value of a day-specific flag, or `default` if it wasn't given.
+    pub fn flag_or<T>(&self, name: &str, default: T) -> Result<T>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        Ok(self.parse_flag(name)?.unwrap_or(default))
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Whether the input was given explicitly, rather than falling back to the embedded one.
+    pub fn has_input(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Read the puzzle input from the path given with `--input`, from stdin for `-`, or else
+    /// fall back to `default`.
+    pub fn read_input(&self, default: Option<&str>) -> Result<String> {
+        let mut input = String::new();
+        self.open_input(default)?.read_to_string(&mut input)?;
+        Ok(input)
+    }
+
+    /// Like `read_input`, but for reading the input a bit at a time instead of all at once.
+    pub fn open_input<'a>(&self, default: Option<&'a str>) -> Result<Box<dyn BufRead + 'a>> {
+        match self.input.as_deref() {
+            Some("-") => Ok(Box::new(std::io::stdin().lock())),
+            Some(path) => {
+                let file = std::fs::File::open(path)
+                    .map_err(|err| format!("Failed to read {}: {}", path, err))?;
+                Ok(Box::new(std::io::BufReader::new(file)))
+            }
+            None => default
+                .map(|input| Box::new(input.as_bytes()) as Box<dyn BufRead>)
+                .ok_or_else(|| "No input given: use --input PATH, or - for stdin".into()),
+        }
+    }
+}
+
+fn usage(name: &str, flags: &[Flag]) -> String {
+    let mut usage = format!("Usage: {} [--input PATH | -] [--format text|json]", name);
+    for (flag, value) in flags {
+        if value.is_empty() {
+            usage.push_str(&format!(" [--{}]", flag));
+        } else {
+            usage.push_str(&format!(" [--{} {}]", flag, value));
+        }
+    }
+    usage.push_str(
+        "\n\nReads the puzzle input from PATH, or from stdin for -. Without either, uses the\n\
+         puzzle input built into the binary, if it has one.\n\n\
+         --format json prints the answers, and how long they took, as JSON.\n",
+    );
+    usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAGS: &[Flag] = &[("preamble", "N"), ("stream", "")];
+
+    fn parse(args: &[&str]) -> Result<Cli> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        Cli::from_args(&args, FLAGS)
+    }
+
+    #[test]
+    fn flags() {
+        let cli = parse(&["--preamble", "5", "--input=in.txt", "--format", "json"]).unwrap();
+        assert_eq!(cli.flag("preamble"), Some("5"));
+        assert_eq!(cli.flag_or("preamble", 25).unwrap(), 5);
+        assert!(cli.has_input());
+        assert_eq!(cli.format(), Format::Json);
+
+        assert!(!cli.switch("stream"));
+
+        let cli = parse(&["--stream", "--preamble=x"]).unwrap();
+        assert!(cli.switch("stream"));
+        assert!(cli.flag_or("preamble", 25).is_err());
+    }
+
+    #[test]
+    fn defaults() {
+        let cli = parse(&[]).unwrap();
+        assert_eq!(cli.flag_or("preamble", 25).unwrap(), 25);
+        assert_eq!(cli.format(), Format::Text);
+        assert_eq!(cli.read_input(Some("embedded")).unwrap(), "embedded");
+        assert!(cli.read_input(None).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse(&["--cups", "123"]).is_err());
+        assert!(parse(&["--preamble"]).is_err());
+        assert!(parse(&["-", "--input", "in.txt"]).is_err());
+        assert!(parse(&["in.txt"]).is_err());
+        assert!(parse(&["--format", "yaml"]).is_err());
+        assert!(parse(&["--stream=yes"]).is_err());
+    }
+}
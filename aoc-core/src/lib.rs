@@ -0,0 +1,26 @@
+mod answer;
+pub mod bench;
+pub mod cli;
+mod parse_error;
+mod report;
+
+pub use answer::Answer;
+pub use parse_error::{parse_number, ParseError};
+pub use report::Report;
+
+pub type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+/// A single day's puzzle.
+///
+/// `parse` does the work shared by both parts, so that each part can be run (and timed) on its
+/// own. The lifetime lets a day borrow from the puzzle input instead of copying it.
+pub trait Solution<'a>: Sized {
+    /// The day of December this puzzle is for.
+    const DAY: u8;
+
+    fn parse(input: &'a str) -> Result<Self, ParseError>;
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+}
@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::str::FromStr;
+
+type Source = Box<dyn Error + Send + Sync>;
+
+/// Something wrong with the puzzle input, and where it is.
+#[derive(Debug)]
+pub struct ParseError {
+    offset: usize,
+    line: usize,
+    column: usize,
+    source: Source,
+}
+
+impl ParseError {
+    /// An error at the start of `span`, which must be a slice of `text`.
+    pub fn at(text: &str, span: &str, source: impl Into<Source>) -> Self {
+        ParseError::at_offset(text, offset_of(text, span), source)
+    }
+
+    /// An error at a byte offset into `text`.
+    pub fn at_offset(text: &str, offset: usize, source: impl Into<Source>) -> Self {
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        ParseError {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source: source.into(),
+        }
+    }
+
+    /// An error for input that stops before it should.
+    pub fn at_end(text: &str, source: impl Into<Source>) -> Self {
+        ParseError::at_offset(text, text.len(), source)
+    }
+
+    /// Move an error found in `inner` to the same place in `outer`, which contains it.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        ParseError::at_offset(outer, offset_of(outer, inner) + self.offset, self.source)
+    }
+
+    /// The same place, but a different explanation of what's wrong there.
+    pub fn with_source(self, source: impl Into<Source>) -> Self {
+        ParseError {
+            source: source.into(),
+            ..self
+        }
+    }
+
+    /// The line of the error, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the error in characters, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The byte offset of the error.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The underlying error, if it's an `E`.
+    pub fn kind<E: Error + 'static>(&self) -> Option<&E> {
+        self.source.downcast_ref()
+    }
+
+    /// A diagnostic quoting the offending line of `input`, with a caret under the error.
+    pub fn render(&self, input: &str) -> String {
+        let text = input.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        format!(
+            "error: {}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}^\n",
+            self.source,
+            gutter,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            text,
+            gutter,
+            " ".repeat(self.column - 1)
+        )
+    }
+
+    /// Print the diagnostic and exit, for binaries that can't go on without their input.
+    pub fn exit(&self, input: &str) -> ! {
+        eprint!("{}", self.render(input));
+        std::process::exit(1)
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.source
+        )
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+fn offset_of(text: &str, span: &str) -> usize {
+    let offset = (span.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|offset| *offset <= text.len());
+    debug_assert!(offset.is_some(), "span isn't part of the text");
+    offset.unwrap_or(0)
+}
+
+/// Parse a number from `span`, a slice of `text`. On failure the error points at the first
+/// character that can't be part of a number.
+pub fn parse_number<T>(text: &str, span: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    span.parse().map_err(|err| {
+        let bad = span
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(0, |(i, _)| i);
+        ParseError::at(text, &span[bad..], err)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_and_column() {
+        let text = "abc\ndéf\nghi";
+        let error = ParseError::at(text, &text[7..], "bad");
+        assert_eq!((error.line(), error.column()), (2, 3));
+
+        let error = ParseError::at_end(text, "bad");
+        assert_eq!((error.line(), error.column()), (3, 4));
+    }
+
+    #[test]
+    fn within() {
+        let text = "first\nsecond line";
+        let line = &text[6..];
+        let error = ParseError::at(line, &line[7..], "bad").within(text, line);
+        assert_eq!((error.line(), error.column()), (2, 8));
+        assert_eq!(error.offset(), 13);
+    }
+
+    #[test]
+    fn number() {
+        let text = "12\n3x4";
+        assert_eq!(parse_number::<u32>(text, &text[..2]).unwrap(), 12);
+
+        let error = parse_number::<u32>(text, &text[3..]).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert!(error.kind::<std::num::ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn render() {
+        let text = "12\n3x4";
+        let error = ParseError::at(text, &text[4..], "Unexpected x");
+        assert_eq!(
+            error.render(text),
+            "error: Unexpected x\n --> line 2, column 2\n  |\n2 | 3x4\n  |  ^\n"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! What a day's binary prints: both answers, either as plain text or as JSON for scripts.
+
+use crate::bench::Timings;
+use crate::cli::Format;
+use crate::{Answer, ParseError, Result, Solution};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+/// The answers to a day, how long they took, and anything else the day wants to report.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Report {
+    pub day: u8,
+    /// `None` for a part that wasn't solved, which serializes as `null`.
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: Timings,
+    #[serde(flatten)]
+    extra: BTreeMap<&'static str, serde_json::Value>,
+}
+
+impl Report {
+    /// Parse the input and solve both parts, timing each step.
+    ///
+    /// This is for binaries: if the input doesn't parse, it prints where and exits.
+    pub fn solve<'a, S: Solution<'a>>(input: &'a str) -> Result<(S, Report)> {
+        Report::solve_with(input, |solution| solution)
+    }
+
+    /// Like `solve`, but lets the day be configured between parsing and solving.
+    pub fn solve_with<'a, S, F>(input: &'a str, configure: F) -> Result<(S, Report)>
+    where
+        S: Solution<'a>,
+        F: FnOnce(S) -> S,
+    {
+        Report::solve_parsed(input, |input| S::parse(input).map(configure))
+    }
+
+    /// Like `solve`, but parses the input with `parse` instead of `S::parse`, for days whose
+    /// parsing can be configured.
+    pub fn solve_parsed<'a, S, F>(input: &'a str, parse: F) -> Result<(S, Report)>
+    where
+        S: Solution<'a>,
+        F: FnOnce(&'a str) -> Result<S, ParseError>,
+    {
+        match Report::solve_parts(input, parse) {
+            (solution, report, None) => Ok((solution, report)),
+            (_, _, Some(err)) => Err(err),
+        }
+    }
+
+    /// Like `solve`, but a part that fails is left out of the report rather than failing it, so
+    /// the other part's answer isn't lost. The first part's error, if any, is returned with it.
+    pub fn solve_partial<'a, S: Solution<'a>>(
+        input: &'a str,
+    ) -> (S, Report, Option<Box<dyn std::error::Error>>) {
+        Report::solve_parts(input, S::parse)
+    }
+
+    fn solve_parts<'a, S, F>(
+        input: &'a str,
+        parse: F,
+    ) -> (S, Report, Option<Box<dyn std::error::Error>>)
+    where
+        S: Solution<'a>,
+        F: FnOnce(&'a str) -> Result<S, ParseError>,
+    {
+        let start = Instant::now();
+        let solution = parse(input).unwrap_or_else(|err| err.exit(input));
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let part1 = solution.part1();
+        let part1_time = start.elapsed();
+
+        let start = Instant::now();
+        let part2 = solution.part2();
+        let part2_time = start.elapsed();
+
+        let timings = Timings::once(parse, part1_time, part2_time);
+        let (part1, err1) = split(part1);
+        let (part2, err2) = split(part2);
+        let report = Report::new(S::DAY, part1, part2, timings);
+        (solution, report, err1.or(err2))
+    }
+
+    /// A report for answers found some other way than through `Solution`, such as while
+    /// streaming the input.
+    pub fn new(day: u8, part1: Option<Answer>, part2: Option<Answer>, timings: Timings) -> Report {
+        Report {
+            day,
+            part1,
+            part2,
+            timings,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Add a day-specific diagnostic to the JSON output.
+    pub fn extra(&mut self, name: &'static str, value: impl serde::Serialize) -> Result<()> {
+        self.extra.insert(name, serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    /// Print the report to stdout. Text is just the answers, one after the other.
+    pub fn print(&self, format: Format) -> Result<()> {
+        match format {
+            Format::Text => {
+                for answer in self.part1.iter().chain(&self.part2) {
+                    println!("{}", answer);
+                }
+            }
+            Format::Json => println!("{}", serde_json::to_string_pretty(self)?),
+        }
+        Ok(())
+    }
+}
+
+fn split<T, E>(result: std::result::Result<T, E>) -> (Option<T>, Option<E>) {
+    match result {
+        Ok(value) => (Some(value), None),
+        Err(err) => (None, Some(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example(u32);
+
+    impl Solution<'_> for Example {
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            Ok(Example(crate::parse_number(input, input)?))
+        }
+
+        fn part1(&self) -> Result<Answer> {
+            Ok(self.0.into())
+        }
+
+        fn part2(&self) -> Result<Answer> {
+            if self.0 == 0 {
+                return Err("no part two for zero".into());
+            }
+            Ok("two".into())
+        }
+    }
+
+    #[test]
+    fn json() {
+        let (_, mut report) = Report::solve::<Example>("12").unwrap();
+        report.extra("note", [1, 2]).unwrap();
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["part1"], 12);
+        assert_eq!(json["part2"], "two");
+        assert_eq!(json["timings"]["runs"], 1);
+        assert_eq!(json["note"], serde_json::json!([1, 2]));
+
+        let report = Report::new(1, Some(3.into()), None, report.timings);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["part1"], 3);
+        assert_eq!(json["part2"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn partial() {
+        let (_, report, err) = Report::solve_partial::<Example>("0");
+        assert_eq!(report.part1, Some(0.into()));
+        assert_eq!(report.part2, None);
+        assert_eq!(err.unwrap().to_string(), "no part two for zero");
+        assert!(Report::solve::<Example>("0").is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use aoc_core::bench::{self, Timings};
+use aoc_core::{Answer, Result, Solution};
+use std::path::{Path, PathBuf};
+
+/// A single day's puzzle, with each part taking the raw puzzle input and returning the answer.
+pub struct Day {
+    pub number: u8,
+    pub part1: fn(&str) -> Result<Answer>,
+    pub part2: fn(&str) -> Result<Answer>,
+    /// Time parsing and both parts over the given number of runs.
+    pub bench: fn(&str, usize) -> Result<Timings>,
+}
+
+impl Day {
+    pub fn get(number: u8) -> Option<&'static Day> {
+        DAYS.iter().find(|day| day.number == number)
+    }
+
+    /// The day's crate directory, which holds its `input.txt`.
+    pub fn dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day-{:02}", self.number))
+    }
+}
+
+macro_rules! day {
+    ($solution:ty) => {{
+        fn part1(input: &str) -> Result<Answer> {
+            <$solution>::parse(input)?.part1()
+        }
+
+        fn part2(input: &str) -> Result<Answer> {
+            <$solution>::parse(input)?.part2()
+        }
+
+        fn bench(input: &str, runs: usize) -> Result<Timings> {
+            bench::bench::<$solution>(input, runs)
+        }
+
+        Day {
+            number: <$solution>::DAY,
+            part1,
+            part2,
+            bench,
+        }
+    }};
+}
+
+pub static DAYS: &[Day] = &[
+    day!(day_01::Day01),
+    day!(day_02::Day02),
+    day!(day_03::Day03),
+    day!(day_04::Day04),
+    day!(day_05::Day05),
+    day!(day_06::Day06),
+    day!(day_07::Day07),
+    day!(day_08::Day08),
+    day!(day_09::Day09),
+    day!(day_10::Day10),
+    day!(day_11::Day11),
+    day!(day_12::Day12),
+    day!(day_13::Day13),
+    day!(day_14::Day14),
+    day!(day_15::Day15),
+    day!(day_16::Day16),
+    day!(day_17::Day17),
+    day!(day_18::Day18),
+    day!(day_19::Day19),
+    day!(day_20::Day20),
+    day!(day_21::Day21),
+    day!(day_22::Day22),
+    day!(day_23::Day23),
+    day!(day_24::Day24),
+    day!(day_25::Day25),
+];
@@ -0,0 +1,160 @@
+//! Expected answers for each day, used to catch refactors that silently change an answer.
+//!
+//! Each day's directory has an `answers.json` mapping an input file (relative to the day's
+//! directory) to the answers expected for it. A day is checked against its `input.txt` and any
+//! puzzle examples saved as `examples/*.txt`.
+
+use crate::Day;
+use aoc_core::{Answer, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, Expected>);
+
+/// The expected answer for each part. A part that is missing isn't checked, which is how
+/// examples that only apply to one part are recorded.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl Answers {
+    pub fn path(day: &Day) -> PathBuf {
+        day.dir().join("answers.json")
+    }
+
+    pub fn load(day: &Day) -> Result<Self> {
+        let path = Answers::path(day);
+        let json = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self, day: &Day) -> Result<()> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        std::fs::write(Answers::path(day), json)?;
+        Ok(())
+    }
+
+    pub fn get(&self, input: &str) -> Option<&Expected> {
+        self.0.get(input)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Expected)> {
+        self.0
+            .iter()
+            .map(|(input, expected)| (input.as_str(), expected))
+    }
+}
+
+/// The inputs a day is checked against: its `input.txt` if it has one, then its examples.
+pub fn inputs(day: &Day) -> Result<Vec<String>> {
+    let dir = day.dir();
+    let mut inputs = Vec::new();
+    if dir.join("input.txt").is_file() {
+        inputs.push("input.txt".to_string());
+    }
+
+    let examples = dir.join("examples");
+    if examples.is_dir() {
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(examples)? {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            if name.ends_with(".txt") {
+                names.push(format!("examples/{}", name));
+            }
+        }
+        names.sort();
+        inputs.extend(names);
+    }
+
+    Ok(inputs)
+}
+
+/// Run every input of a day and compare against its `answers.json`, returning a description of
+/// each answer that doesn't match.
+pub fn check(day: &Day) -> Result<Vec<String>> {
+    let answers = Answers::load(day)?;
+    let mut mismatches = Vec::new();
+
+    for input in inputs(day)? {
+        if answers.get(&input).is_none() {
+            mismatches.push(format!("{}: no answers recorded", input));
+        }
+    }
+
+    for (input, expected) in answers.iter() {
+        let text = std::fs::read_to_string(day.dir().join(input))
+            .map_err(|err| format!("Failed to read {}: {}", input, err))?;
+
+        let parts = [
+            (1, day.part1, &expected.part1),
+            (2, day.part2, &expected.part2),
+        ];
+        for (part, solve, expected) in parts.iter() {
+            let expected = match expected {
+                Some(expected) => expected,
+                None => continue,
+            };
+            match solve(&text) {
+                Ok(actual) if actual == *expected => {}
+                Ok(actual) => mismatches.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    input, part, expected, actual
+                )),
+                Err(err) => mismatches.push(format!(
+                    "{} part {}: expected {}, got error: {}",
+                    input, part, expected, err
+                )),
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
+
+/// Record the current answers for every input of a day.
+///
+/// Inputs that already have answers keep the same set of parts, so a part that was deliberately
+/// left out of an example stays out. New inputs record every part that succeeds.
+pub fn bless(day: &Day) -> Result<Answers> {
+    let old = Answers::load(day).unwrap_or_default();
+    let mut new = Answers::default();
+
+    for input in inputs(day)? {
+        let text = std::fs::read_to_string(day.dir().join(&input))?;
+        let previous = old.get(&input);
+
+        let expected = Expected {
+            part1: bless_part(&input, &text, 1, day.part1, previous.map(|p| &p.part1))?,
+            part2: bless_part(&input, &text, 2, day.part2, previous.map(|p| &p.part2))?,
+        };
+        new.0.insert(input, expected);
+    }
+
+    new.save(day)?;
+    Ok(new)
+}
+
+fn bless_part(
+    input: &str,
+    text: &str,
+    part: u8,
+    solve: fn(&str) -> Result<Answer>,
+    previous: Option<&Option<Answer>>,
+) -> Result<Option<Answer>> {
+    match previous {
+        None => Ok(solve(text).ok()),
+        Some(None) => Ok(None),
+        Some(Some(_)) => match solve(text) {
+            Ok(answer) => Ok(Some(answer)),
+            Err(err) => Err(format!("{} part {}: {}", input, part, err).into()),
+        },
+    }
+}
@@ -0,0 +1,4 @@
+pub mod days;
+pub mod golden;
+
+pub use days::{Day, DAYS};
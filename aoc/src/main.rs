@@ -0,0 +1,403 @@
+use aoc::{golden, Day, DAYS};
+use aoc_core::bench::{format_duration, Stats, Timings};
+use aoc_core::{Answer, ParseError};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+const USAGE: &str = "\
+Usage: aoc run [DAY...] [--part 1|2] [--input PATH]
+       aoc bench [DAY...] [--runs N] [--format table|json] [--input PATH]
+       aoc bless [DAY...]
+
+`run` runs the solvers for the given days, or for every day if no days are
+given. A day is either a number from 1 to 25, or an inclusive range such as 3-7.
+Days without an input.txt are skipped by `run` and `bench`.
+
+`bench` times parsing and each part separately over a number of runs, and
+reports the min, median and max of each. Build with --release first.
+
+`bless` records the current answers for each day's input.txt and examples in
+its answers.json, which the golden tests compare against.
+
+Options:
+    --part 1|2              Only run the given part
+    --input PATH            Read the puzzle input from PATH, or from stdin if PATH
+                            is -, instead of the day's input.txt (only allowed
+                            with a single day)
+    --runs N                How many times to run each day when benchmarking
+                            [default: 10]
+    --format table|json     How to report benchmark timings [default: table]
+";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => RunArgs::parse(&args[1..]).and_then(|args| run(&args)),
+        Some("bench") => BenchArgs::parse(&args[1..]).and_then(|args| bench(&args)),
+        Some("bless") => parse_day_list(&args[1..]).and_then(|days| bless(&days)),
+        Some("help") | Some("--help") | Some("-h") => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format!("Unknown command '{}'\n\n{}", command, USAGE).into()),
+        None => Err(USAGE.into()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Part {
+    One,
+    Two,
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RunArgs {
+    days: Vec<u8>,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+}
+
+impl RunArgs {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut run_args = RunArgs::default();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let part = args.next().ok_or("--part requires a value")?;
+                    run_args.part = Some(match part.as_str() {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => return Err(format!("Invalid part '{}'", part).into()),
+                    });
+                }
+                "--input" => {
+                    let input = args.next().ok_or("--input requires a value")?;
+                    run_args.input = Some(PathBuf::from(input));
+                }
+                days => run_args.days.extend(parse_days(days)?),
+            }
+        }
+
+        if run_args.days.is_empty() {
+            run_args.days = all_days();
+        }
+        if run_args.input.is_some() && run_args.days.len() != 1 {
+            return Err("--input can only be used when running a single day".into());
+        }
+
+        Ok(run_args)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    days: Vec<u8>,
+    runs: usize,
+    format: Format,
+    input: Option<PathBuf>,
+}
+
+impl BenchArgs {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut bench_args = BenchArgs {
+            days: Vec::new(),
+            runs: 10,
+            format: Format::Table,
+            input: None,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--runs" => {
+                    let runs = args.next().ok_or("--runs requires a value")?;
+                    bench_args.runs = match runs.parse() {
+                        Ok(runs) if runs > 0 => runs,
+                        _ => return Err(format!("Invalid number of runs '{}'", runs).into()),
+                    };
+                }
+                "--format" => {
+                    let format = args.next().ok_or("--format requires a value")?;
+                    bench_args.format = match format.as_str() {
+                        "table" => Format::Table,
+                        "json" => Format::Json,
+                        _ => return Err(format!("Invalid format '{}'", format).into()),
+                    };
+                }
+                "--input" => {
+                    let input = args.next().ok_or("--input requires a value")?;
+                    bench_args.input = Some(PathBuf::from(input));
+                }
+                days => bench_args.days.extend(parse_days(days)?),
+            }
+        }
+
+        if bench_args.days.is_empty() {
+            bench_args.days = all_days();
+        }
+        if bench_args.input.is_some() && bench_args.days.len() != 1 {
+            return Err("--input can only be used when benchmarking a single day".into());
+        }
+
+        Ok(bench_args)
+    }
+}
+
+fn all_days() -> Vec<u8> {
+    DAYS.iter().map(|day| day.number).collect()
+}
+
+/// Parse a list of days and ranges of days, defaulting to every day.
+fn parse_day_list(args: &[String]) -> Result<Vec<u8>> {
+    let mut days = Vec::new();
+    for arg in args {
+        days.extend(parse_days(arg)?);
+    }
+    if days.is_empty() {
+        days = all_days();
+    }
+    Ok(days)
+}
+
+/// Parse either a single day (`17`) or an inclusive range of days (`3-7`). A range that runs
+/// backwards (`7-3`) is an error, rather than no days.
+fn parse_days(s: &str) -> Result<Vec<u8>> {
+    let parse_day = |s: &str| -> Result<u8> {
+        let number = s
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid day '{}'", s))?;
+        if Day::get(number).is_none() {
+            return Err(format!("There is no day {}", number).into());
+        }
+        Ok(number)
+    };
+
+    if let Some(dash) = s.find('-') {
+        let start = parse_day(&s[..dash])?;
+        let end = parse_day(&s[(dash + 1)..])?;
+        if start > end {
+            return Err(
+                format!("Invalid range '{}', the first day comes after the last", s).into(),
+            );
+        }
+        Ok((start..=end).collect())
+    } else {
+        Ok(vec![parse_day(s)?])
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let mut failures = 0;
+
+    for &number in &args.days {
+        let day = Day::get(number).ok_or_else(|| format!("There is no day {}", number))?;
+        let path = match &args.input {
+            Some(path) => path.clone(),
+            None => day.dir().join("input.txt"),
+        };
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) if args.input.is_none() && err.kind() == std::io::ErrorKind::NotFound => {
+                println!("Day {}: skipped, there's no input.txt", day.number);
+                continue;
+            }
+            Err(err) => {
+                println!(
+                    "Day {}: failed to read {}: {}",
+                    day.number,
+                    path.display(),
+                    err
+                );
+                failures += 1;
+                continue;
+            }
+        };
+
+        for part in args.parts() {
+            let solve = match part {
+                Part::One => day.part1,
+                Part::Two => day.part2,
+            };
+            match solve(&input) {
+                Ok(answer) => println!("Day {} part {}: {}", day.number, part, answer),
+                Err(err) => {
+                    failures += 1;
+                    if let Some(err) = err.downcast_ref::<ParseError>() {
+                        println!("Day {}: failed to parse {}", day.number, path.display());
+                        eprint!("{}", err.render(&input));
+                        // Both parts parse the same way, so the other part would fail too.
+                        break;
+                    }
+                    println!("Day {} part {}: error: {}", day.number, part, err);
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        Err(format!("{} failures", failures).into())
+    } else {
+        Ok(())
+    }
+}
+
+fn bench(args: &BenchArgs) -> Result<()> {
+    let mut failures = 0;
+    let mut results = Vec::new();
+
+    if args.format == Format::Table {
+        println!(
+            "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}",
+            "day", "step", "min", "median", "max"
+        );
+    }
+
+    for &number in &args.days {
+        let day = Day::get(number).ok_or_else(|| format!("There is no day {}", number))?;
+        let input = match &args.input {
+            Some(path) => read_input(path)
+                .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?,
+            None => match std::fs::read_to_string(day.dir().join("input.txt")) {
+                Ok(input) => input,
+                Err(_) => {
+                    eprintln!("Day {}: skipped, there's no input.txt", day.number);
+                    continue;
+                }
+            },
+        };
+
+        let timings = match (day.bench)(&input, args.runs) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("Day {}: error: {}", day.number, err);
+                failures += 1;
+                continue;
+            }
+        };
+
+        match args.format {
+            Format::Table => {
+                let steps = [
+                    ("parse", timings.parse),
+                    ("part1", timings.part1),
+                    ("part2", timings.part2),
+                ];
+                for (step, stats) in steps.iter() {
+                    print_stats(day.number, step, stats);
+                }
+            }
+            Format::Json => results.push(BenchResult {
+                day: day.number,
+                timings,
+            }),
+        }
+    }
+
+    if args.format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    }
+
+    if failures > 0 {
+        Err(format!("{} failures", failures).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(serde::Serialize)]
+struct BenchResult {
+    day: u8,
+    #[serde(flatten)]
+    timings: Timings,
+}
+
+fn print_stats(day: u8, step: &str, stats: &Stats) {
+    println!(
+        "{:>3}  {:<5}  {:>9}  {:>9}  {:>9}",
+        day,
+        step,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.max)
+    );
+}
+
+fn bless(days: &[u8]) -> Result<()> {
+    for &number in days {
+        let day = Day::get(number).ok_or_else(|| format!("There is no day {}", number))?;
+        let old = golden::Answers::load(day).unwrap_or_default();
+        let new = golden::bless(day)?;
+
+        for (input, expected) in new.iter() {
+            let previous = old.get(input).cloned().unwrap_or_default();
+            let parts = [
+                (1, &previous.part1, &expected.part1),
+                (2, &previous.part2, &expected.part2),
+            ];
+            for (part, previous, expected) in parts.iter() {
+                if previous != expected {
+                    println!(
+                        "Day {} {} part {}: {} -> {}",
+                        day.number,
+                        input,
+                        part,
+                        display(previous),
+                        display(expected)
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Read a puzzle input from a file, or from stdin if the path is `-`.
+fn read_input(path: &Path) -> std::io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+fn display(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(answer) => answer.to_string(),
+        None => "-".to_string(),
+    }
+}
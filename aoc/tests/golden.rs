@@ -0,0 +1,54 @@
+//! Checks every day against the answers recorded in its `answers.json`. After an intentional
+//! change to an answer, run `aoc bless DAY` to record the new one.
+
+use aoc::{golden, Day};
+
+fn check(number: u8) {
+    let day = Day::get(number).unwrap();
+    let mismatches = golden::check(day).unwrap();
+    assert!(
+        mismatches.is_empty(),
+        "Day {} doesn't match its answers.json:\n{}",
+        number,
+        mismatches.join("\n")
+    );
+}
+
+macro_rules! golden {
+    ($($name:ident => $number:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($number);
+            }
+        )*
+    };
+}
+
+golden! {
+    day_01 => 1,
+    day_02 => 2,
+    day_03 => 3,
+    day_04 => 4,
+    day_05 => 5,
+    day_06 => 6,
+    day_07 => 7,
+    day_08 => 8,
+    day_09 => 9,
+    day_10 => 10,
+    day_11 => 11,
+    day_12 => 12,
+    day_13 => 13,
+    day_14 => 14,
+    day_15 => 15,
+    day_16 => 16,
+    day_17 => 17,
+    day_18 => 18,
+    day_19 => 19,
+    day_20 => 20,
+    day_21 => 21,
+    day_22 => 22,
+    day_23 => 23,
+    day_24 => 24,
+    day_25 => 25,
+}
@@ -0,0 +1,68 @@
+//! Compares ways of finding a pair of entries that sum to a target on large generated inputs:
+//! the original nested loops, `find_k_sum`'s sort and two pointers, and `find_pair`'s single pass,
+//! both over parsed numbers and streaming from text.
+//!
+//! Run with `cargo bench -p day-01`.
+
+use aoc_core::bench::{print_header, time_runs, Lcg};
+
+const TARGET: u64 = 4_000_000;
+const RUNS: usize = 5;
+
+fn main() {
+    print_header("entries", "approach");
+    for &len in &[1_000, 10_000, 20_000, 1_000_000] {
+        let numbers = generate(len);
+        let text: String = numbers.iter().map(|n| format!("{}\n", n)).collect();
+        let expected = Some(numbers[len - 1]);
+
+        // Quadratic, so only run on the smaller inputs.
+        if len <= 20_000 {
+            time_runs(len, "nested loops", RUNS, || {
+                nested_loops(&numbers).first().map(|&(_, m)| m)
+            });
+        }
+        let two_pointers = time_runs(len, "two pointers", RUNS, || {
+            let pairs = day_01::find_k_sum(&numbers, 2, TARGET);
+            pairs.first().map(|pair| pair[1])
+        });
+        let single_pass = time_runs(len, "single pass", RUNS, || {
+            day_01::find_pair(numbers.iter().copied(), TARGET).map(|(_, m)| m)
+        });
+        let streaming = time_runs(len, "streaming", RUNS, || {
+            let entries = day_01::entries(text.as_bytes()).map(Result::unwrap);
+            day_01::find_pair(entries, TARGET).map(|(_, m)| m)
+        });
+
+        assert_eq!(two_pointers, expected);
+        assert_eq!(single_pass, expected);
+        assert_eq!(streaming, expected);
+    }
+}
+
+/// `len` entries where only the last two sum to the target, so that every approach
+/// has to look at everything.
+fn generate(len: usize) -> Vec<u64> {
+    // Every entry but the last two is 1 more than a multiple of 4, so no two of them can sum to
+    // the target, which is a multiple of 4.
+    let mut lcg = Lcg::new(0x2020);
+    let mut numbers: Vec<u64> = (0..(len - 2))
+        .map(|_| lcg.below(TARGET / 4) * 4 + 1)
+        .collect();
+    numbers.push(1_000_000);
+    numbers.push(TARGET - 1_000_000);
+    numbers
+}
+
+/// How part one was originally solved: every ordered pair, including each entry with itself.
+fn nested_loops(numbers: &[u64]) -> Vec<(u64, u64)> {
+    let mut pairs = Vec::new();
+    for n in numbers {
+        for m in numbers {
+            if n + m == TARGET {
+                pairs.push((*n, *m));
+            }
+        }
+    }
+    pairs
+}
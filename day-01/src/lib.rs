@@ -0,0 +1,189 @@
+mod stream;
+
+pub use stream::{entries, find_pair, Entries, EntryError};
+
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::cmp::Ordering;
+
+pub const DEFAULT_TARGET: u64 = 2020;
+
+pub struct Day01 {
+    numbers: Vec<u64>,
+    target: u64,
+}
+
+impl Day01 {
+    /// Look for entries that sum to something other than the puzzle's 2020.
+    pub fn with_target(self, target: u64) -> Self {
+        Day01 { target, ..self }
+    }
+
+    /// Every distinct combination of `k` entries that sums to the target.
+    pub fn combinations(&self, k: usize) -> Vec<Vec<u64>> {
+        find_k_sum(&self.numbers, k, self.target)
+    }
+
+    /// The product of the first combination of `k` entries that sums to the target.
+    fn product(&self, k: usize) -> Result<Answer> {
+        let combination = self
+            .combinations(k)
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No {} entries sum to {}", k, self.target))?;
+        Ok(product(&combination)?.into())
+    }
+}
+
+impl Solution<'_> for Day01 {
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let numbers = input
+            .lines()
+            .map(|line| parse_number(input, line))
+            .collect::<Result<Vec<u64>, _>>()?;
+        Ok(Day01 {
+            numbers,
+            target: DEFAULT_TARGET,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        self.product(2)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.product(3)
+    }
+}
+
+/// The product of some entries, or an error if it doesn't fit in a `u64`.
+pub fn product(numbers: &[u64]) -> Result<u64> {
+    numbers
+        .iter()
+        .try_fold(1_u64, |product, n| product.checked_mul(*n))
+        .ok_or_else(|| "The product is too large".into())
+}
+
+/// Every distinct combination of `k` different entries that sums to `target`, each sorted in
+/// ascending order.
+///
+/// Entries are different if they're on different lines, so a number can only be used twice if it
+/// appears twice. Combinations are distinct by value, so each one is returned once however many
+/// ways there are to pick it. This takes O(n^(k-1)) time for k >= 2, e.g. O(n²) for three entries.
+pub fn find_k_sum(numbers: &[u64], k: usize, target: u64) -> Vec<Vec<u64>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+
+    let mut combinations = Vec::new();
+    let mut prefix = Vec::with_capacity(k);
+    k_sum(&sorted, k, target, &mut prefix, &mut combinations);
+    combinations
+}
+
+fn k_sum(
+    sorted: &[u64],
+    k: usize,
+    target: u64,
+    prefix: &mut Vec<u64>,
+    combinations: &mut Vec<Vec<u64>>,
+) {
+    match k {
+        0 => {
+            if target == 0 {
+                combinations.push(prefix.clone());
+            }
+        }
+        1 => {
+            if sorted.binary_search(&target).is_ok() {
+                let mut combination = prefix.clone();
+                combination.push(target);
+                combinations.push(combination);
+            }
+        }
+        2 => two_sum(sorted, target, prefix, combinations),
+        _ => {
+            for (i, &n) in sorted.iter().enumerate() {
+                // Every entry from here on is at least n, so the remaining k - 1 can't be smaller.
+                if n.saturating_mul(k as u64) > target {
+                    break;
+                }
+                // Starting from the same value again would only find the same combinations.
+                if i > 0 && sorted[i - 1] == n {
+                    continue;
+                }
+                prefix.push(n);
+                k_sum(&sorted[(i + 1)..], k - 1, target - n, prefix, combinations);
+                prefix.pop();
+            }
+        }
+    }
+}
+
+/// Find pairs from both ends of the sorted entries at once, moving whichever end makes the sum
+/// closer to the target.
+fn two_sum(sorted: &[u64], target: u64, prefix: &[u64], combinations: &mut Vec<Vec<u64>>) {
+    if sorted.len() < 2 {
+        return;
+    }
+
+    let (mut low, mut high) = (0, sorted.len() - 1);
+    while low < high {
+        let (n, m) = (sorted[low], sorted[high]);
+        let sum = n.checked_add(m);
+        match sum.map_or(Ordering::Greater, |sum| sum.cmp(&target)) {
+            Ordering::Less => low += 1,
+            Ordering::Greater => high -= 1,
+            Ordering::Equal => {
+                let mut combination = prefix.to_vec();
+                combination.extend_from_slice(&[n, m]);
+                combinations.push(combination);
+
+                while low < high && sorted[low] == n {
+                    low += 1;
+                }
+                while low < high && sorted[high] == m {
+                    high -= 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &[u64] = &[1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn example() {
+        assert_eq!(find_k_sum(EXAMPLE, 2, 2020), vec![vec![299, 1721]]);
+        assert_eq!(find_k_sum(EXAMPLE, 3, 2020), vec![vec![366, 675, 979]]);
+    }
+
+    #[test]
+    fn distinct_entries() {
+        // 1010 can't be paired with itself, but two entries of 1010 can be paired.
+        assert!(find_k_sum(&[1010, 5], 2, 2020).is_empty());
+        assert_eq!(
+            find_k_sum(&[1010, 1010, 1010], 2, 2020),
+            vec![vec![1010, 1010]]
+        );
+
+        // Each combination is returned once, however many ways there are to pick it.
+        assert_eq!(
+            find_k_sum(&[1, 3, 2, 2, 3, 1], 2, 4),
+            vec![vec![1, 3], vec![2, 2]]
+        );
+    }
+
+    #[test]
+    fn any_k() {
+        let numbers = [1, 2, 3, 4, 5];
+        assert_eq!(find_k_sum(&numbers, 0, 0), vec![Vec::<u64>::new()]);
+        assert_eq!(find_k_sum(&numbers, 1, 4), vec![vec![4]]);
+        assert_eq!(find_k_sum(&numbers, 4, 12), vec![vec![1, 2, 4, 5]]);
+        assert!(find_k_sum(&numbers, 6, 15).is_empty());
+    }
+}
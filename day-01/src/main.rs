@@ -1,28 +1,100 @@
-use std::io::BufRead;
+use aoc_core::bench::Timings;
+use aoc_core::cli::{Cli, Format};
+use aoc_core::Report;
+use day_01::{Day01, EntryError};
+use std::time::{Duration, Instant};
 
-fn main() {
-    let stdin = std::io::stdin();
-    let lines = stdin.lock().lines();
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
 
-    let numbers: Vec<_> = lines
-        .map(|line| line.unwrap().parse::<u64>().unwrap())
-        .collect();
+fn main() -> Result<()> {
+    let cli = Cli::parse(&[("target", "N"), ("k", "K"), ("stream", "")]);
+    let target = cli.flag_or("target", day_01::DEFAULT_TARGET)?;
+    let k = cli.parse_flag::<usize>("k")?;
 
-    for n in &numbers {
-        for m in &numbers {
-            if n + m == 2020 {
-                println!("n = {}, m = {}, n*m = {}", n, m, n * m);
+    if cli.switch("stream") {
+        if k.is_some() {
+            return Err("--stream only looks for pairs, so it can't be used with --k".into());
+        }
+        return stream(&cli, target);
+    }
+
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
+    let (day, mut report) = Report::solve_with(&input, |day: Day01| day.with_target(target))?;
+
+    // The parts always look for two and three entries. With --k, also list every combination of
+    // that many entries.
+    let k = match k {
+        Some(k) => k,
+        None => return report.print(cli.format()),
+    };
+    let combinations = day.combinations(k);
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            for combination in &combinations {
+                let terms: Vec<String> = combination.iter().map(u64::to_string).collect();
+                println!("{} = {}", terms.join(" + "), target);
+            }
+        }
+        Format::Json => {
+            report.extra("combinations", combinations)?;
+            report.print(Format::Json)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find a pair for part one without reading the whole input into memory first. Lines that aren't
+/// numbers are skipped and reported, rather than stopping everything.
+fn stream(cli: &Cli, target: u64) -> Result<()> {
+    let start = Instant::now();
+    let mut entries = day_01::entries(cli.open_input(Some(include_str!("../input.txt")))?);
+    let mut bad_lines = Vec::new();
+    let mut io_error = None;
+    let mut numbers = std::iter::from_fn(|| loop {
+        match entries.next()? {
+            Ok(n) => return Some(n),
+            Err(EntryError::BadLine { line, text, source }) => bad_lines.push((line, text, source)),
+            Err(err) => {
+                io_error = Some(err);
+                return None;
             }
         }
+    });
+
+    let pair = day_01::find_pair(numbers.by_ref(), target);
+    // Keep reading, so that every bad line is reported and not just the ones before the pair.
+    numbers.for_each(drop);
+    if let Some(err) = io_error {
+        return Err(err.into());
     }
 
-    for l in &numbers {
-        for n in &numbers {
-            for m in &numbers {
-                if l + n + m == 2020 {
-                    println!("l = {}, n = {}, m = {}, l*n*m = {}", l, n, m, l * n * m);
-                }
+    let (n, m) = pair.ok_or_else(|| format!("No two entries sum to {}", target))?;
+    let product = day_01::product(&[n, m])?;
+    // Reading and searching happen together, so it's all part 1's time. Part 2 needs every entry
+    // at once, so streaming doesn't solve it.
+    let timings = Timings::once(Duration::ZERO, start.elapsed(), Duration::ZERO);
+    let mut report = Report::new(1, Some(product.into()), None, timings);
+    match cli.format() {
+        Format::Text => {
+            for (line, text, err) in &bad_lines {
+                eprintln!("skipped line {}: {}: {:?}", line, err, text);
             }
+            report.print(Format::Text)?;
+        }
+        Format::Json => {
+            let bad_lines: Vec<_> = bad_lines
+                .iter()
+                .map(|(line, text, err)| {
+                    serde_json::json!({ "line": line, "text": text, "error": err.to_string() })
+                })
+                .collect();
+            report.extra("pair", [n, m])?;
+            report.extra("bad_lines", bad_lines)?;
+            report.print(Format::Json)?;
         }
     }
+
+    Ok(())
 }
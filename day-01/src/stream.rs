@@ -0,0 +1,158 @@
+//! For expense reports too big to comfortably hold in memory as text: entries are read a line at
+//! a time, and pairs are found in a single pass.
+
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// Read entries from `reader` a line at a time, skipping blank lines.
+///
+/// A line that isn't a number is returned as an error, and reading carries on with the next
+/// line. Stops after the first I/O error.
+pub fn entries<R: BufRead>(reader: R) -> Entries<R> {
+    Entries {
+        reader,
+        buffer: String::new(),
+        line: 0,
+        failed: false,
+    }
+}
+
+pub struct Entries<R> {
+    reader: R,
+    /// Reused for every line, so reading doesn't allocate once the longest line has been seen.
+    buffer: String,
+    line: usize,
+    failed: bool,
+}
+
+impl<R: BufRead> Iterator for Entries<R> {
+    type Item = Result<u64, EntryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            self.buffer.clear();
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => {
+                    self.failed = true;
+                    return Some(Err(err.into()));
+                }
+            }
+            self.line += 1;
+
+            let text = self.buffer.trim();
+            if text.is_empty() {
+                continue;
+            }
+            return Some(text.parse().map_err(|source| EntryError::BadLine {
+                line: self.line,
+                text: text.to_string(),
+                source,
+            }));
+        }
+        None
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EntryError {
+    #[error("line {line}: {source}: {text:?}")]
+    BadLine {
+        line: usize,
+        text: String,
+        source: std::num::ParseIntError,
+    },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Targets up to this size use a bitset with one bit per possible entry, which is 2MiB at most.
+/// Larger targets fall back to hashing.
+const BITSET_LIMIT: u64 = 1 << 24;
+
+/// The first pair of different entries that sums to `target`, in a single pass over the entries.
+///
+/// Each entry is checked against the entries before it, by looking up `target - entry` in the set
+/// of entries seen so far. So the pair is returned as soon as its second entry is read.
+pub fn find_pair(entries: impl IntoIterator<Item = u64>, target: u64) -> Option<(u64, u64)> {
+    let mut seen = Seen::new(target);
+    for entry in entries {
+        // An entry larger than the target can't be in a pair, since entries aren't negative.
+        if entry > target {
+            continue;
+        }
+        if seen.contains(target - entry) {
+            return Some((target - entry, entry));
+        }
+        seen.insert(entry);
+    }
+    None
+}
+
+/// A set of entries, none of them larger than the target.
+enum Seen {
+    Bits(Vec<u64>),
+    Hashed(HashSet<u64>),
+}
+
+impl Seen {
+    fn new(target: u64) -> Seen {
+        if target < BITSET_LIMIT {
+            Seen::Bits(vec![0; (target / 64 + 1) as usize])
+        } else {
+            Seen::Hashed(HashSet::new())
+        }
+    }
+
+    fn contains(&self, entry: u64) -> bool {
+        match self {
+            Seen::Bits(bits) => bits[(entry / 64) as usize] & (1 << (entry % 64)) != 0,
+            Seen::Hashed(set) => set.contains(&entry),
+        }
+    }
+
+    fn insert(&mut self, entry: u64) {
+        match self {
+            Seen::Bits(bits) => bits[(entry / 64) as usize] |= 1 << (entry % 64),
+            Seen::Hashed(set) => {
+                set.insert(entry);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_entries() {
+        let input = "1721\n\n979\n36x\n  366 \n";
+        let entries: Vec<_> = entries(input.as_bytes()).collect();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0].as_ref().unwrap(), &1721);
+        assert_eq!(entries[3].as_ref().unwrap(), &366);
+        match &entries[2] {
+            Err(EntryError::BadLine { line, text, .. }) => {
+                assert_eq!((*line, text.as_str()), (4, "36x"))
+            }
+            other => panic!("Expected a bad line, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pairs() {
+        let numbers = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(find_pair(numbers.iter().copied(), 2020), Some((1721, 299)));
+        assert_eq!(find_pair(vec![1010, 5], 2020), None);
+        assert_eq!(find_pair(vec![1010, 5, 1010], 2020), Some((1010, 1010)));
+
+        // Too big for the bitset.
+        let target = BITSET_LIMIT * 4;
+        assert_eq!(
+            find_pair(vec![1, target - 2, 2], target),
+            Some((target - 2, 2))
+        );
+    }
+}
@@ -0,0 +1,215 @@
+mod policy;
+mod validation;
+
+pub use policy::*;
+pub use validation::*;
+
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct Day02 {
+    entries: Vec<PasswordEntry>,
+}
+
+impl Day02 {
+    /// How many entries the policy accepts.
+    pub fn count_valid(&self, policy: &dyn Policy) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| policy.is_valid(entry))
+            .count()
+    }
+}
+
+impl Solution<'_> for Day02 {
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let entries = input
+            .lines()
+            .map(|line| {
+                line.parse::<PasswordEntry>()
+                    .map_err(|err| err.within(input, line))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Day02 { entries })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.count_valid(&CountInRange).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.count_valid(&ExactlyOnePosition).into())
+    }
+}
+
+/// A rule and a password. The letter, and the positions in the password, are graphemes, so a
+/// letter with an accent counts as one letter however it's encoded.
+#[derive(Debug)]
+pub struct PasswordEntry {
+    min: usize,
+    max: usize,
+    letter: String,
+    password: String,
+}
+
+impl PasswordEntry {
+    /// The first number of the rule.
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    /// The second number of the rule.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// The letter of the rule, a single grapheme.
+    pub fn letter(&self) -> &str {
+        &self.letter
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    /// The graphemes of the password, which are what the rule's positions count.
+    pub fn graphemes(&self) -> impl Iterator<Item = &str> {
+        self.password.graphemes(true)
+    }
+
+    /// How many times the letter appears in the password.
+    pub fn letter_count(&self) -> usize {
+        self.graphemes().filter(|g| *g == self.letter).count()
+    }
+
+    /// Valid according to the `CountInRange` policy.
+    pub fn is_valid(&self) -> bool {
+        CountInRange.is_valid(self)
+    }
+
+    /// Valid according to the `ExactlyOnePosition` policy.
+    pub fn is_valid_2(&self) -> bool {
+        ExactlyOnePosition.is_valid(self)
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum PasswordEntryParseError {
+    #[error("Missing Range")]
+    MissingRange,
+    #[error("Missing Character")]
+    MissingCharacter,
+    #[error("Missing Password")]
+    MissingPassword,
+    #[error("Invalid Range")]
+    InvalidRange,
+    #[error("Invalid Character")]
+    InvalidCharacter,
+    #[error("Positions start at 1")]
+    ZeroPosition,
+    #[error("Range minimum is greater than its maximum")]
+    InvertedRange,
+}
+
+impl std::str::FromStr for PasswordEntry {
+    type Err = ParseError;
+
+    /// Parse a line such as `1-3 a: abcde`. Errors point into the line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ' ');
+        let range = parts
+            .next()
+            .ok_or_else(|| ParseError::at(s, s, PasswordEntryParseError::MissingRange))?;
+        let letter = parts
+            .next()
+            .ok_or_else(|| ParseError::at_end(s, PasswordEntryParseError::MissingCharacter))?;
+        let password = parts
+            .next()
+            .ok_or_else(|| ParseError::at_end(s, PasswordEntryParseError::MissingPassword))?
+            .to_string();
+
+        let invalid_range =
+            |err: ParseError| err.with_source(PasswordEntryParseError::InvalidRange);
+        let mut range_parts = range.splitn(2, '-');
+        let min = range_parts
+            .next()
+            .ok_or_else(|| ParseError::at(s, range, PasswordEntryParseError::InvalidRange))?;
+        let min = parse_number(s, min).map_err(invalid_range)?;
+        let max = range_parts.next().ok_or_else(|| {
+            ParseError::at(
+                s,
+                &range[range.len()..],
+                PasswordEntryParseError::InvalidRange,
+            )
+        })?;
+        let max = parse_number(s, max).map_err(invalid_range)?;
+
+        if min == 0 {
+            return Err(ParseError::at(
+                s,
+                range,
+                PasswordEntryParseError::ZeroPosition,
+            ));
+        }
+        if min > max {
+            return Err(ParseError::at(
+                s,
+                range,
+                PasswordEntryParseError::InvertedRange,
+            ));
+        }
+
+        // The letter is followed by a colon, and must be exactly one grapheme.
+        let letter = letter.strip_suffix(':').unwrap_or(letter);
+        let mut graphemes = letter.graphemes(true);
+        let letter = match (graphemes.next(), graphemes.next()) {
+            (Some(letter), None) => letter.to_string(),
+            _ => {
+                return Err(ParseError::at(
+                    s,
+                    letter,
+                    PasswordEntryParseError::InvalidCharacter,
+                ))
+            }
+        };
+
+        Ok(PasswordEntry {
+            min,
+            max,
+            letter,
+            password,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(line: &str) -> PasswordEntryParseError {
+        let err = line.parse::<PasswordEntry>().unwrap_err();
+        *err.kind::<PasswordEntryParseError>().unwrap()
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(error("0-3 a: abc"), PasswordEntryParseError::ZeroPosition);
+        assert_eq!(error("3-1 a: abc"), PasswordEntryParseError::InvertedRange);
+        assert_eq!(
+            error("1-3 ab: abc"),
+            PasswordEntryParseError::InvalidCharacter
+        );
+        assert_eq!(
+            error("1-3 : abc"),
+            PasswordEntryParseError::InvalidCharacter
+        );
+        assert_eq!(error("1-3 a:"), PasswordEntryParseError::MissingPassword);
+
+        // One grapheme made of two code points is a single letter.
+        let entry: PasswordEntry = "1-1 e\u{301}: e\u{301}".parse().unwrap();
+        assert_eq!(entry.letter(), "e\u{301}");
+        assert_eq!(entry.letter_count(), 1);
+    }
+}
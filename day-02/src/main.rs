@@ -1,94 +1,60 @@
-use std::io::BufRead;
-
-fn main() {
-    let stdin = std::io::stdin();
-    let lines = stdin.lock().lines();
-
-    let entries: Vec<_> = lines
-        .map(|line| line.unwrap().parse::<PasswordEntry>().unwrap())
-        .collect();
-
-    let num_valid = entries.iter().filter(|entry| entry.is_valid()).count();
-    println!("{}", num_valid);
-
-    let num_valid = entries.iter().filter(|entry| entry.is_valid_2()).count();
-    println!("{}", num_valid);
-}
-
-#[derive(Debug)]
-struct PasswordEntry {
-    min: usize,
-    max: usize,
-    ch: char,
-    password: String,
-}
-
-impl PasswordEntry {
-    fn is_valid(&self) -> bool {
-        let count = self.password.chars().filter(|c| *c == self.ch).count();
-        self.min <= count && count <= self.max
+use aoc_core::bench::Timings;
+use aoc_core::cli::{Cli, Format};
+use aoc_core::Report;
+use day_02::Day02;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(&[("policy", "NAME,..."), ("report", "")]);
+    let policies = match cli.flag("policy") {
+        Some("all") => day_02::builtin_policies(),
+        Some(names) => names
+            .split(',')
+            .map(day_02::policy_by_name)
+            .collect::<Result<_, _>>()?,
+        None => Vec::new(),
+    };
+
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
+    if cli.switch("report") {
+        // Unlike solving, the report carries on past malformed lines, so it can list all of them.
+        // That leaves it without the puzzle's answers, and all of its time is checking entries.
+        let start = Instant::now();
+        let validation = day_02::validate(&input);
+        let timings = Timings::once(start.elapsed(), Duration::ZERO, Duration::ZERO);
+        match cli.format() {
+            Format::Text => print!("{}", validation),
+            Format::Json => {
+                let mut report = Report::new(2, None, None, timings);
+                report.extra("validation", validation)?;
+                report.print(Format::Json)?;
+            }
+        }
+        return Ok(());
     }
 
-    fn is_valid_2(&self) -> bool {
-        let chars: Vec<char> = self.password.chars().collect();
-        let c1 = chars.get(self.min - 1);
-        let c2 = chars.get(self.max - 1);
-        (c1 == Some(&self.ch)) ^ (c2 == Some(&self.ch))
+    let (day, mut report) = Report::solve::<Day02>(&input)?;
+    if policies.is_empty() {
+        return report.print(cli.format());
     }
-}
-
-#[derive(Debug, thiserror::Error, Clone, Copy)]
-enum PasswordEntryParseError {
-    #[error("Missing Range")]
-    MissingRange,
-    #[error("Missing Character")]
-    MissingCharacter,
-    #[error("Missing Password")]
-    MissingPassword,
-    #[error("Invalid Range")]
-    InvalidRange,
-    #[error("Invalid Character")]
-    InvalidCharacter,
-}
-
-impl std::str::FromStr for PasswordEntry {
-    type Err = PasswordEntryParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.splitn(3, ' ');
-        let range = parts.next().ok_or(PasswordEntryParseError::MissingRange)?;
-        let ch = parts
-            .next()
-            .ok_or(PasswordEntryParseError::MissingCharacter)?;
-        let password = parts
-            .next()
-            .ok_or(PasswordEntryParseError::MissingPassword)?
-            .to_string();
 
-        let mut range_parts = range.splitn(2, '-');
-        let min = range_parts
-            .next()
-            .ok_or(PasswordEntryParseError::InvalidRange)?;
-        let min = min
-            .parse()
-            .map_err(|_| PasswordEntryParseError::InvalidRange)?;
-        let max = range_parts
-            .next()
-            .ok_or(PasswordEntryParseError::InvalidRange)?;
-        let max = max
-            .parse()
-            .map_err(|_| PasswordEntryParseError::InvalidRange)?;
-
-        let ch = ch
-            .chars()
-            .next()
-            .ok_or(PasswordEntryParseError::InvalidCharacter)?;
-
-        Ok(PasswordEntry {
-            min,
-            max,
-            ch,
-            password,
-        })
+    let counts: Vec<(String, usize)> = policies
+        .iter()
+        .map(|policy| (policy.name(), day.count_valid(policy.as_ref())))
+        .collect();
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            for (name, count) in counts {
+                println!("{}: {}", name, count);
+            }
+        }
+        Format::Json => {
+            report.extra("policies", counts.into_iter().collect::<BTreeMap<_, _>>())?;
+            report.print(Format::Json)?;
+        }
     }
+
+    Ok(())
 }
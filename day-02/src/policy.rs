@@ -0,0 +1,188 @@
+use crate::PasswordEntry;
+use aoc_core::Result;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A way of deciding whether a password is valid, given the rule on its line: two numbers and a
+/// letter.
+pub trait Policy {
+    /// The name the policy is chosen by on the command line.
+    fn name(&self) -> String;
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool;
+}
+
+/// The sled rental place's policy: the letter appears between min and max times.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CountInRange;
+
+impl Policy for CountInRange {
+    fn name(&self) -> String {
+        "count".to_string()
+    }
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        let count = entry.letter_count();
+        entry.min() <= count && count <= entry.max()
+    }
+}
+
+/// The Official Toboggan Corporate Policy: the letter is at exactly one of the two positions,
+/// counting graphemes from 1.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExactlyOnePosition;
+
+impl Policy for ExactlyOnePosition {
+    fn name(&self) -> String {
+        "exactly-one".to_string()
+    }
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        let (first, second) = positions(entry);
+        first ^ second
+    }
+}
+
+/// The letter is at one or both of the two positions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AtLeastOnePosition;
+
+impl Policy for AtLeastOnePosition {
+    fn name(&self) -> String {
+        "at-least-one".to_string()
+    }
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        let (first, second) = positions(entry);
+        first || second
+    }
+}
+
+/// Whether the letter is at each of the two positions.
+pub(crate) fn positions(entry: &PasswordEntry) -> (bool, bool) {
+    let at = |position: usize| {
+        position
+            .checked_sub(1)
+            .and_then(|index| entry.graphemes().nth(index))
+            == Some(entry.letter())
+    };
+    (at(entry.min()), at(entry.max()))
+}
+
+/// The password is between min and max graphemes long. The letter doesn't matter.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LengthInRange;
+
+impl Policy for LengthInRange {
+    fn name(&self) -> String {
+        "length".to_string()
+    }
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        let length = entry.graphemes().count();
+        entry.min() <= length && length <= entry.max()
+    }
+}
+
+/// The password doesn't contain any of a set of graphemes. The rule doesn't matter.
+#[derive(Debug, Clone, Default)]
+pub struct ForbiddenCharacters(pub Vec<String>);
+
+impl ForbiddenCharacters {
+    /// Forbid each grapheme of `chars`.
+    pub fn new(chars: &str) -> Self {
+        ForbiddenCharacters(chars.graphemes(true).map(str::to_string).collect())
+    }
+}
+
+impl Policy for ForbiddenCharacters {
+    fn name(&self) -> String {
+        format!("forbidden:{}", self.0.concat())
+    }
+
+    fn is_valid(&self, entry: &PasswordEntry) -> bool {
+        !entry
+            .graphemes()
+            .any(|grapheme| self.0.iter().any(|forbidden| forbidden == grapheme))
+    }
+}
+
+/// Every policy that can be chosen by name alone.
+pub fn builtin_policies() -> Vec<Box<dyn Policy>> {
+    vec![
+        Box::new(CountInRange),
+        Box::new(ExactlyOnePosition),
+        Box::new(AtLeastOnePosition),
+        Box::new(LengthInRange),
+    ]
+}
+
+/// Choose a policy by name. `forbidden:CHARS` forbids each of the characters after the colon.
+pub fn policy_by_name(name: &str) -> Result<Box<dyn Policy>> {
+    if let Some(chars) = name.strip_prefix("forbidden:") {
+        return Ok(Box::new(ForbiddenCharacters::new(chars)));
+    }
+    builtin_policies()
+        .into_iter()
+        .find(|policy| policy.name() == name)
+        .ok_or_else(|| {
+            let names: Vec<String> = builtin_policies().iter().map(|p| p.name()).collect();
+            format!(
+                "Unknown policy '{}', expected one of {} or forbidden:CHARS",
+                name,
+                names.join(", ")
+            )
+            .into()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid(policy: &str, line: &str) -> bool {
+        let entry: PasswordEntry = line.parse().unwrap();
+        policy_by_name(policy).unwrap().is_valid(&entry)
+    }
+
+    #[test]
+    fn examples() {
+        assert!(valid("count", "1-3 a: abcde"));
+        assert!(!valid("count", "1-3 b: cdefg"));
+        assert!(valid("count", "2-9 c: ccccccccc"));
+
+        assert!(valid("exactly-one", "1-3 a: abcde"));
+        assert!(!valid("exactly-one", "1-3 b: cdefg"));
+        assert!(!valid("exactly-one", "2-9 c: ccccccccc"));
+    }
+
+    #[test]
+    fn more_policies() {
+        assert!(valid("at-least-one", "2-9 c: ccccccccc"));
+        assert!(!valid("at-least-one", "1-3 b: cdefg"));
+
+        assert!(valid("length", "1-5 a: abcde"));
+        assert!(!valid("length", "1-4 a: abcde"));
+
+        assert!(valid("forbidden:xyz", "1-3 a: abcde"));
+        assert!(!valid("forbidden:xyz", "1-3 a: abcdz"));
+
+        assert!(policy_by_name("nope").is_err());
+    }
+
+    #[test]
+    fn graphemes() {
+        // "é" as "e" and a combining accent is one letter, in one position.
+        assert!(valid("count", "2-2 e\u{301}: e\u{301}xe\u{301}"));
+        assert!(valid("exactly-one", "1-2 e\u{301}: e\u{301}xe\u{301}"));
+        assert!(!valid("exactly-one", "1-3 e\u{301}: e\u{301}xe\u{301}"));
+        assert!(!valid("count", "1-3 e: e\u{301}"));
+
+        assert!(valid("exactly-one", "2-3 ß: aßc"));
+        assert!(valid("exactly-one", "1-4 🇳🇱: 🇳🇱🇧🇪"));
+        assert!(valid("length", "2-2 a: 🇳🇱🇧🇪"));
+
+        assert!(valid("forbidden:e", "1-1 a: e\u{301}"));
+        assert!(!valid("forbidden:e\u{301}", "1-1 a: e\u{301}"));
+        assert!(valid("forbidden:e\u{301}", "1-1 a: e"));
+    }
+}
@@ -0,0 +1,193 @@
+//! Explains why each password fails the puzzle's policies, for someone reviewing the rejects.
+
+use crate::policy::positions;
+use crate::{CountInRange, ExactlyOnePosition, PasswordEntry, PasswordEntryParseError, Policy};
+use serde::Serialize;
+
+/// Every entry that fails either of the puzzle's policies, and every line that isn't an entry.
+#[derive(Debug, Default, Serialize)]
+pub struct ValidationReport {
+    pub rejected: Vec<Rejection>,
+    pub malformed: Vec<Malformed>,
+}
+
+/// An entry that fails at least one policy, with what each policy saw.
+#[derive(Debug, Serialize)]
+pub struct Rejection {
+    pub line: usize,
+    pub text: String,
+    pub count: CountCheck,
+    pub positions: PositionCheck,
+}
+
+/// How many times the letter appears, against the range it's allowed.
+#[derive(Debug, Serialize)]
+pub struct CountCheck {
+    pub valid: bool,
+    pub count: usize,
+    pub min: usize,
+    pub max: usize,
+}
+
+/// Which of the two positions have the letter. Exactly one of them should.
+#[derive(Debug, Serialize)]
+pub struct PositionCheck {
+    pub valid: bool,
+    pub positions: [usize; 2],
+    pub matched: Vec<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Malformed {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub error: PasswordEntryParseError,
+}
+
+/// Check every line of the input, carrying on past lines that can't be parsed.
+pub fn validate(input: &str) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    for (i, line) in input.lines().enumerate() {
+        let entry = match line.parse::<PasswordEntry>() {
+            Ok(entry) => entry,
+            Err(err) => {
+                report.malformed.push(Malformed {
+                    line: i + 1,
+                    column: err.column(),
+                    text: line.to_string(),
+                    // Every error from parsing an entry is one of these.
+                    error: *err
+                        .kind::<PasswordEntryParseError>()
+                        .expect("a password entry parse error"),
+                });
+                continue;
+            }
+        };
+
+        let count = CountCheck {
+            valid: CountInRange.is_valid(&entry),
+            count: entry.letter_count(),
+            min: entry.min(),
+            max: entry.max(),
+        };
+        let (first, second) = positions(&entry);
+        let matched = [(first, entry.min()), (second, entry.max())]
+            .iter()
+            .filter(|(matched, _)| *matched)
+            .map(|(_, position)| *position)
+            .collect();
+        let positions = PositionCheck {
+            valid: ExactlyOnePosition.is_valid(&entry),
+            positions: [entry.min(), entry.max()],
+            matched,
+        };
+
+        if !count.valid || !positions.valid {
+            report.rejected.push(Rejection {
+                line: i + 1,
+                text: line.to_string(),
+                count,
+                positions,
+            });
+        }
+    }
+    report
+}
+
+impl std::fmt::Display for ValidationReport {
+    /// A table of the rejected entries, followed by the malformed lines.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .rejected
+            .iter()
+            .map(|rejection| rejection.text.chars().count())
+            .chain(std::iter::once("entry".len()))
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "{:>5}  {:<width$}  {:<16}  positions",
+            "line",
+            "entry",
+            "count",
+            width = width
+        )?;
+        for rejection in &self.rejected {
+            let count = &rejection.count;
+            let count = if count.valid {
+                "ok".to_string()
+            } else {
+                format!("{} not in {}-{}", count.count, count.min, count.max)
+            };
+            let positions = &rejection.positions;
+            let matched = match positions.matched.len() {
+                0 => "neither".to_string(),
+                2 => "both".to_string(),
+                _ => format!("only {}", positions.matched[0]),
+            };
+            let positions = if positions.valid {
+                "ok".to_string()
+            } else {
+                format!(
+                    "{} of {} and {}",
+                    matched, positions.positions[0], positions.positions[1]
+                )
+            };
+            writeln!(
+                f,
+                "{:>5}  {:<width$}  {:<16}  {}",
+                rejection.line,
+                rejection.text,
+                count,
+                positions,
+                width = width
+            )?;
+        }
+
+        if !self.malformed.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "malformed:")?;
+            for malformed in &self.malformed {
+                writeln!(
+                    f,
+                    "{:>5}  column {}: {:?} ({}): {}",
+                    malformed.line,
+                    malformed.column,
+                    malformed.error,
+                    malformed.error,
+                    malformed.text
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let report = validate("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-3\n");
+        assert_eq!(report.rejected.len(), 2);
+
+        let rejection = &report.rejected[0];
+        assert_eq!(rejection.line, 2);
+        assert!(!rejection.count.valid);
+        assert_eq!(rejection.count.count, 0);
+        assert!(rejection.positions.matched.is_empty());
+
+        let rejection = &report.rejected[1];
+        assert!(rejection.count.valid);
+        assert_eq!(rejection.positions.matched, vec![2, 9]);
+
+        assert_eq!(report.malformed.len(), 1);
+        assert_eq!(report.malformed[0].line, 4);
+        assert_eq!(
+            report.malformed[0].error,
+            PasswordEntryParseError::MissingCharacter
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Compares counting trees on very tall generated hills stored the original way, as a `Vec<bool>`
+//! per row, against `Hill`'s single packed buffer.
+//!
+//! Run with `cargo bench -p day-03`.
+
+use aoc_core::bench::{print_header, time_runs, Lcg};
+use day_03::{Hill, PART2_SLOPES};
+
+const WIDTH: usize = 31;
+const RUNS: usize = 5;
+
+fn main() {
+    print_header("rows", "storage");
+    for &height in &[1_000, 100_000, 1_000_000, 4_000_000] {
+        let text = generate(height);
+        let rows = Rows::parse(&text);
+        let hill: Hill = text.parse().unwrap();
+
+        let expected = time_runs(height, "Vec<Vec<bool>>", RUNS, || {
+            PART2_SLOPES
+                .iter()
+                .map(|&(x, y)| rows.trees_on_slope(x as usize, y))
+                .collect::<Vec<_>>()
+        });
+        let packed = time_runs(height, "packed bits", RUNS, || {
+            PART2_SLOPES
+                .iter()
+                .map(|&(x, y)| hill.trees_on_slope(x, y).unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(packed, expected);
+    }
+}
+
+/// A hill `height` rows tall with trees scattered about a quarter of the time.
+fn generate(height: usize) -> String {
+    let mut lcg = Lcg::new(0x2020);
+    let mut text = String::with_capacity((WIDTH + 1) * height);
+    for _ in 0..height {
+        for _ in 0..WIDTH {
+            text.push(if lcg.below(4) == 0 { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// How the hill was originally stored: a separate allocation for each row.
+struct Rows(Vec<Vec<bool>>);
+
+impl Rows {
+    fn parse(text: &str) -> Rows {
+        Rows(
+            text.lines()
+                .map(|line| line.chars().map(|ch| ch == '#').collect())
+                .collect(),
+        )
+    }
+
+    fn trees_on_slope(&self, x_slope: usize, y_slope: usize) -> usize {
+        let mut trees = 0;
+        let (mut x, mut y) = (x_slope, y_slope);
+        while y < self.0.len() {
+            let row = &self.0[y];
+            if row[x % row.len()] {
+                trees += 1;
+            }
+            x += x_slope;
+            y += y_slope;
+        }
+        trees
+    }
+}
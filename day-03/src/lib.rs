@@ -0,0 +1,364 @@
+mod render;
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+
+pub struct Day03 {
+    hill: Hill,
+}
+
+impl Day03 {
+    pub fn hill(&self) -> &Hill {
+        &self.hill
+    }
+}
+
+impl Solution<'_> for Day03 {
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Day03 {
+            hill: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.hill.trees_on_slope(3, 1)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut m = 1;
+        for (x, y) in PART2_SLOPES {
+            let r = self.hill.trees_on_slope(*x, *y)?;
+            m *= r;
+        }
+        Ok(m.into())
+    }
+}
+
+/// The slopes whose tree counts are multiplied together for part 2, as (right, down).
+pub const PART2_SLOPES: &[(isize, usize)] = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+/// The map of the hill, one bit per square, row after row in a single buffer.
+#[derive(Debug)]
+pub struct Hill {
+    trees: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl Hill {
+    fn tree_at_point(&self, x: usize, y: usize) -> Result<bool, HillError> {
+        if y >= self.height {
+            return Err(HillError::OutOfRange(x, y));
+        }
+
+        let wrapped_x = x % self.width;
+
+        Ok(self.get(wrapped_x, y))
+    }
+
+    /// Whether there's a tree at a point, which must be on the map.
+    fn get(&self, x: usize, y: usize) -> bool {
+        let index = y * self.width + x;
+        self.trees[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// How many trees are hit going `x_slope` right and `y_slope` down each step, starting from
+    /// the top left. A negative `x_slope` goes left, wrapping around to the right edge.
+    pub fn trees_on_slope(&self, x_slope: isize, y_slope: usize) -> Result<usize, HillError> {
+        if y_slope == 0 {
+            return Err(HillError::FlatSlope(x_slope));
+        }
+
+        // Going left is the same as going right most of the way around, so x only ever
+        // increases, and stays wrapped without dividing.
+        let x_step = x_slope.rem_euclid(self.width as isize) as usize;
+
+        let mut trees = 0;
+        // We don't count (0,0), so start (0+x_slope, 0+y_slope)
+        let mut current_x = x_step;
+        let mut current_y = y_slope;
+
+        while current_y < self.height {
+            if self.get(current_x, current_y) {
+                trees += 1;
+            }
+            current_x += x_step;
+            if current_x >= self.width {
+                current_x -= self.width;
+            }
+            current_y += y_slope;
+        }
+
+        Ok(trees)
+    }
+
+    /// Every point visited going down a slope, in order, and whether it's a tree. Like
+    /// `trees_on_slope`, this doesn't include the starting point.
+    ///
+    /// The x coordinates aren't wrapped, so they keep counting past the right edge, or below zero
+    /// going left, as if the map were repeated.
+    pub fn trace(&self, x_slope: isize, y_slope: usize) -> Result<Vec<Step>, HillError> {
+        if y_slope == 0 {
+            return Err(HillError::FlatSlope(x_slope));
+        }
+
+        let mut steps = Vec::with_capacity(self.height / y_slope);
+        let mut x = x_slope;
+        for y in (y_slope..self.height).step_by(y_slope) {
+            let wrapped_x = x.rem_euclid(self.width as isize) as usize;
+            steps.push(Step {
+                x,
+                y,
+                tree: self.tree_at_point(wrapped_x, y)?,
+            });
+            x += x_slope;
+        }
+        Ok(steps)
+    }
+
+    /// The trees on every slope going at most `max_x` left or right and between 1 and `max_y`
+    /// down each step, ordered by how far down and then from left to right.
+    pub fn slope_counts(&self, max_x: usize, max_y: usize) -> Result<Vec<SlopeCount>, HillError> {
+        let max_x = max_x as isize;
+        let mut counts = Vec::new();
+        for y in 1..=max_y {
+            for x in -max_x..=max_x {
+                counts.push(SlopeCount {
+                    x,
+                    y,
+                    trees: self.trees_on_slope(x, y)?,
+                });
+            }
+        }
+        Ok(counts)
+    }
+
+    /// The slope within the bounds of `slope_counts` that hits the fewest or most trees. Ties go
+    /// to the first slope in the order `slope_counts` returns them.
+    pub fn best_slope(
+        &self,
+        max_x: usize,
+        max_y: usize,
+        objective: Objective,
+    ) -> Result<Option<SlopeCount>, HillError> {
+        let counts = self.slope_counts(max_x, max_y)?;
+        Ok(objective.best(&counts))
+    }
+}
+
+/// A point on a route down the hill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Step {
+    pub x: isize,
+    pub y: usize,
+    pub tree: bool,
+}
+
+/// The trees hit on one slope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct SlopeCount {
+    pub x: isize,
+    pub y: usize,
+    pub trees: usize,
+}
+
+/// Whether the best slope is the one with the fewest trees or the most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Fewest,
+    Most,
+}
+
+impl Objective {
+    /// The best of some slope counts, or `None` if there are none.
+    pub fn best(self, counts: &[SlopeCount]) -> Option<SlopeCount> {
+        counts.iter().copied().fold(None, |best, count| match best {
+            Some(best) if !self.better(count, best) => Some(best),
+            _ => Some(count),
+        })
+    }
+
+    fn better(self, a: SlopeCount, b: SlopeCount) -> bool {
+        match self {
+            Objective::Fewest => a.trees < b.trees,
+            Objective::Most => a.trees > b.trees,
+        }
+    }
+}
+
+impl std::str::FromStr for Objective {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fewest" => Ok(Objective::Fewest),
+            "most" => Ok(Objective::Most),
+            _ => Err(format!(
+                "Unknown objective '{}', expected fewest or most",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HillError {
+    #[error("Out of range: {0}x{1} is out of range")]
+    OutOfRange(usize, usize),
+    #[error("Slope {0} right, 0 down never reaches the bottom")]
+    FlatSlope(isize),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HillParseError {
+    #[error("Inconsistent width")]
+    InconsistentWidth,
+    #[error("Invalid character: {0}")]
+    InvalidCharacter(char),
+    #[error("Empty map")]
+    Empty,
+}
+
+impl std::str::FromStr for Hill {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.trim().split('\n');
+
+        let mut trees = Vec::new();
+        let mut len = 0;
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in lines {
+            let start = len;
+
+            for (i, ch) in line.char_indices() {
+                let tree = match ch {
+                    '.' => false,
+                    '#' => true,
+                    _ => {
+                        return Err(ParseError::at(
+                            s,
+                            &line[i..],
+                            HillParseError::InvalidCharacter(ch),
+                        ))
+                    }
+                };
+                if len % 64 == 0 {
+                    trees.push(0);
+                }
+                if tree {
+                    trees[len / 64] |= 1 << (len % 64);
+                }
+                len += 1;
+            }
+
+            let row_width = len - start;
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                let at = &line[width.min(line.len())..];
+                return Err(ParseError::at(s, at, HillParseError::InconsistentWidth));
+            }
+
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseError::at_end(s, HillParseError::Empty));
+        }
+
+        Ok(Hill {
+            trees,
+            width,
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn example() {
+        let hill: Hill = EXAMPLE.parse().unwrap();
+        let counts: Vec<usize> = PART2_SLOPES
+            .iter()
+            .map(|(x, y)| hill.trees_on_slope(*x, *y).unwrap())
+            .collect();
+        assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+    }
+
+    #[test]
+    fn negative_slopes() {
+        let hill: Hill = EXAMPLE.parse().unwrap();
+        // The hill is 11 wide, so 8 left lands in the same places as 3 right.
+        assert_eq!(hill.trees_on_slope(-8, 1).unwrap(), 7);
+        assert_eq!(hill.trees_on_slope(-19, 1).unwrap(), 7);
+        assert!(hill.trees_on_slope(1, 0).is_err());
+    }
+
+    #[test]
+    fn bounds() {
+        let hill: Hill = EXAMPLE.parse().unwrap();
+        assert!(hill.tree_at_point(3, 10).is_ok());
+        assert!(hill.tree_at_point(3, 11).is_err());
+        assert!(hill.tree_at_point(12, 10).unwrap());
+
+        assert!("".parse::<Hill>().is_err());
+        assert!("..#\n.#\n".parse::<Hill>().is_err());
+    }
+
+    #[test]
+    fn trace() {
+        let hill: Hill = EXAMPLE.parse().unwrap();
+        let steps = hill.trace(3, 1).unwrap();
+        assert_eq!(steps.len(), 10);
+        assert_eq!(steps.iter().filter(|step| step.tree).count(), 7);
+        assert_eq!(
+            steps[0],
+            Step {
+                x: 3,
+                y: 1,
+                tree: false
+            }
+        );
+        assert_eq!(steps[9].x, 30);
+
+        let steps = hill.trace(-8, 1).unwrap();
+        assert_eq!(steps[1].x, -16);
+        assert_eq!(steps.iter().filter(|step| step.tree).count(), 7);
+    }
+
+    #[test]
+    fn search() {
+        let hill: Hill = EXAMPLE.parse().unwrap();
+        let counts = hill.slope_counts(7, 2).unwrap();
+        assert_eq!(counts.len(), 30);
+
+        let most = hill.best_slope(7, 2, Objective::Most).unwrap().unwrap();
+        assert!(counts.iter().all(|count| count.trees <= most.trees));
+        assert!(most.trees >= 7);
+
+        let fewest = hill.best_slope(7, 2, Objective::Fewest).unwrap().unwrap();
+        assert!(counts.iter().all(|count| count.trees >= fewest.trees));
+        assert!(hill.best_slope(0, 0, Objective::Most).unwrap().is_none());
+    }
+}
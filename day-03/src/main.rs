@@ -1,121 +1,84 @@
-use std::io::Read;
-
-fn main() -> Result<()> {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input)?;
-    let hill = input.parse::<Hill>()?;
-
-    let r = hill.trees_on_slope(3, 1)?;
-    println!("{}", r);
-
-    let slopes = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
-    let mut m = 1;
-    for (x, y) in slopes {
-        let r = hill.trees_on_slope(*x, *y)?;
-        m *= r;
+use aoc_core::cli::{Cli, Format};
+use aoc_core::Report;
+use day_03::{Day03, Objective};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(&[
+        ("search", "X,Y"),
+        ("objective", "fewest|most"),
+        ("trace", "X,Y"),
+        ("svg", "PATH"),
+    ]);
+    let objective = cli.flag_or("objective", Objective::Fewest)?;
+    let bounds = pair::<usize, usize>(&cli, "search")?;
+    let slope = pair::<isize, usize>(&cli, "trace")?;
+    if cli.flag("svg").is_some() && slope.is_none() {
+        return Err("--svg draws the route given with --trace".into());
     }
-    println!("{}", m);
-
-    Ok(())
-}
-
-type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
 
-#[derive(Debug)]
-struct Hill {
-    trees: Vec<Vec<bool>>,
-    width: usize,
-    height: usize,
-}
-
-impl Hill {
-    fn tree_at_point(&self, x: usize, y: usize) -> Result<bool, HillError> {
-        if y > self.height {
-            return Err(HillError::OutOfRange(x, y));
-        }
-
-        let wrapped_x = x % self.width;
-
-        let row = self
-            .trees
-            .get(y)
-            .ok_or_else(|| HillError::OutOfRange(x, y))?;
-        let tree = row
-            .get(wrapped_x)
-            .ok_or_else(|| HillError::OutOfRange(x, y))?;
-
-        Ok(*tree)
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
+    let (day, mut report) = Report::solve::<Day03>(&input)?;
+    let hill = day.hill();
+    let counts = match bounds {
+        Some((max_x, max_y)) => Some(hill.slope_counts(max_x, max_y)?),
+        None => None,
+    };
+    let steps = match slope {
+        Some((x, y)) => Some(hill.trace(x, y)?),
+        None => None,
+    };
+    if let (Some(steps), Some(path)) = (&steps, cli.flag("svg")) {
+        let mut file = std::fs::File::create(path)?;
+        hill.write_svg(steps, &mut file)?;
     }
 
-    fn trees_on_slope(&self, x_slope: usize, y_slope: usize) -> Result<usize, HillError> {
-        let mut trees = 0;
-        // We don't count (0,0), so start (0+x_slope, 0+y_slope)
-        let mut current_x = x_slope;
-        let mut current_y = y_slope;
-
-        while current_y < self.height {
-            if self.tree_at_point(current_x, current_y)? {
-                trees += 1;
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            if let Some(counts) = &counts {
+                println!("{:>5}  {:>5}  {:>5}", "right", "down", "trees");
+                for count in counts {
+                    println!("{:>5}  {:>5}  {:>5}", count.x, count.y, count.trees);
+                }
+                if let Some(best) = objective.best(counts) {
+                    println!(
+                        "{:?}: {} right, {} down hits {} trees",
+                        objective, best.x, best.y, best.trees
+                    );
+                }
+            }
+            if let Some(steps) = &steps {
+                print!("{}", hill.render(steps));
             }
-            current_x += x_slope;
-            current_y += y_slope;
         }
-
-        Ok(trees)
-    }
-}
-
-#[derive(Debug, thiserror::Error)]
-enum HillError {
-    #[error("Out of range: {0}x{1} is out of range")]
-    OutOfRange(usize, usize),
-}
-
-#[derive(Debug, thiserror::Error)]
-enum HillParseError {
-    #[error("Inconsistent width")]
-    InconsistentWidth,
-    #[error("Invalid character: {0}")]
-    InvalidCharacter(char),
-}
-
-impl std::str::FromStr for Hill {
-    type Err = HillParseError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines = s.trim().split('\n');
-
-        let mut trees = Vec::new();
-        let mut width = 0;
-        let mut height = 0;
-
-        for line in lines {
-            let chars = line.chars();
-            let mut row = Vec::new();
-
-            for ch in chars {
-                match ch {
-                    '.' => row.push(false),
-                    '#' => row.push(true),
-                    _ => return Err(HillParseError::InvalidCharacter(ch)),
-                }
+        Format::Json => {
+            if let Some(counts) = &counts {
+                report.extra("slopes", counts)?;
+                report.extra("best", objective.best(counts))?;
             }
-
-            if width == 0 {
-                width = row.len();
-            } else if row.len() != width {
-                return Err(HillParseError::InconsistentWidth);
+            if let Some(steps) = &steps {
+                report.extra("trace", steps)?;
             }
-
-            trees.push(row);
-
-            height += 1;
+            report.print(Format::Json)?;
         }
+    }
+
+    Ok(())
+}
 
-        Ok(Hill {
-            trees,
-            width,
-            height,
-        })
+/// A flag given as two comma-separated numbers, such as `--search 7,2`.
+fn pair<A, B>(cli: &Cli, name: &str) -> Result<Option<(A, B)>, Box<dyn std::error::Error>>
+where
+    A: std::str::FromStr,
+    B: std::str::FromStr,
+{
+    let value = match cli.flag(name) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    let mut parts = value.splitn(2, ',');
+    match (parts.next().map(str::parse), parts.next().map(str::parse)) {
+        (Some(Ok(a)), Some(Ok(b))) => Ok(Some((a, b))),
+        _ => Err(format!("Invalid --{} '{}', expected X,Y", name, value).into()),
     }
 }
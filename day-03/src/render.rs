@@ -0,0 +1,120 @@
+//! Drawing a route down the hill, either as text like the puzzle's or as an SVG.
+
+use crate::{Hill, Step};
+use std::collections::HashMap;
+use std::io::Write;
+
+impl Hill {
+    /// The map with a route drawn over it, as in the puzzle: `X` where the route hits a tree and
+    /// `O` where it misses. The map is repeated to the left and right as far as the route goes.
+    pub fn render(&self, steps: &[Step]) -> String {
+        let columns = self.columns(steps);
+        let steps: HashMap<(isize, usize), bool> = steps
+            .iter()
+            .map(|step| ((step.x, step.y), step.tree))
+            .collect();
+
+        let mut out = String::with_capacity((columns.len() + 1) * self.height);
+        for y in 0..self.height {
+            for x in columns.clone() {
+                out.push(match steps.get(&(x, y)) {
+                    Some(true) => 'X',
+                    Some(false) => 'O',
+                    None if self.tree(x, y) => '#',
+                    None => '.',
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Like `render`, but as an SVG, which is easier to look at for long routes. The route is
+    /// drawn as a line from the top left, with hits in red and misses in white.
+    pub fn write_svg<W: Write>(&self, steps: &[Step], writer: &mut W) -> std::io::Result<()> {
+        let columns = self.columns(steps);
+        let left = columns.start;
+        writeln!(
+            writer,
+            r##"<svg version="1.0" xmlns="http://www.w3.org/2000/svg" width="100%" height="100%" viewBox="0 0 {width} {height}">"##,
+            width = columns.len(),
+            height = self.height
+        )?;
+        writeln!(
+            writer,
+            r##"  <rect x="0" y="0" width="{width}" height="{height}" fill="#eeeeff"/>"##,
+            width = columns.len(),
+            height = self.height
+        )?;
+        for y in 0..self.height {
+            for x in columns.clone() {
+                if self.tree(x, y) {
+                    writeln!(
+                        writer,
+                        r##"  <rect x="{x}" y="{y}" width="1" height="1" fill="#006600"/>"##,
+                        x = x - left,
+                        y = y
+                    )?;
+                }
+            }
+        }
+
+        let points: Vec<String> = std::iter::once((0, 0))
+            .chain(steps.iter().map(|step| (step.x, step.y)))
+            .map(|(x, y)| format!("{}.5,{}.5", x - left, y))
+            .collect();
+        writeln!(
+            writer,
+            r##"  <polyline points="{}" fill="none" stroke="#333333" stroke-width="0.2"/>"##,
+            points.join(" ")
+        )?;
+        for step in steps {
+            writeln!(
+                writer,
+                r##"  <circle cx="{x}.5" cy="{y}.5" r="0.4" fill="{fill}"/>"##,
+                x = step.x - left,
+                y = step.y,
+                fill = if step.tree { "#ff0000" } else { "#ffffff" }
+            )?;
+        }
+
+        writeln!(writer, "</svg>")?;
+        Ok(())
+    }
+
+    /// The columns to draw: whole copies of the map, enough to cover the start and the route.
+    fn columns(&self, steps: &[Step]) -> std::ops::Range<isize> {
+        let width = self.width as isize;
+        let xs = || steps.iter().map(|step| step.x).chain(std::iter::once(0));
+        let first = xs().min().unwrap_or(0).div_euclid(width);
+        let last = xs().max().unwrap_or(0).div_euclid(width);
+        (first * width)..((last + 1) * width)
+    }
+
+    /// Whether there's a tree at a point on the repeated map.
+    fn tree(&self, x: isize, y: usize) -> bool {
+        self.get(x.rem_euclid(self.width as isize) as usize, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Hill;
+
+    #[test]
+    fn render() {
+        let hill: Hill = "..##.\n#...#\n.#...\n".parse().unwrap();
+        let steps = hill.trace(3, 1).unwrap();
+        assert_eq!(hill.render(&steps), "..##...##.\n#..O##...#\n.#....X...\n");
+
+        // Going left repeats the map to the left.
+        let steps = hill.trace(-1, 1).unwrap();
+        assert_eq!(hill.render(&steps), "..##...##.\n#...X#...#\n.#.O..#...\n");
+
+        let mut svg = Vec::new();
+        hill.write_svg(&steps, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(r#"viewBox="0 0 10 3""#));
+        assert!(svg.contains(r#"points="5.5,0.5 4.5,1.5 3.5,2.5""#));
+    }
+}
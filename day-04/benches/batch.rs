@@ -0,0 +1,130 @@
+//! Compares validating large generated batches of passports the original way, compiling each
+//! regex every time it's used, against the schema's cached regexes, on one thread and on all of
+//! them.
+//!
+//! Run with `cargo bench -p day-04`.
+
+use aoc_core::bench::{print_header, time_runs, Lcg};
+use day_04::{Passport, Schema, UnknownKeys};
+
+const RUNS: usize = 3;
+
+fn main() {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    print_header("passports", "approach");
+    for &len in &[10_000, 100_000, 1_000_000] {
+        let input = generate(len);
+
+        // Slow, so only run on the smaller inputs.
+        let original = if len <= 100_000 {
+            Some(time_runs(len, "original", RUNS, || {
+                input
+                    .split("\n\n")
+                    .map(|block| block.parse::<Passport>().unwrap())
+                    .filter(original_is_valid)
+                    .count()
+            }))
+        } else {
+            None
+        };
+        let single = time_runs(len, "one thread", RUNS, || {
+            day_04::validate_batch(&input, Schema::puzzle(), UnknownKeys::Strict, 1)
+                .unwrap()
+                .valid
+        });
+        let label = format!("threads: {}", threads);
+        let parallel = time_runs(len, &label, RUNS, || {
+            day_04::validate_batch(&input, Schema::puzzle(), UnknownKeys::Strict, threads)
+                .unwrap()
+                .valid
+        });
+
+        assert_eq!(single, parallel);
+        if let Some(original) = original {
+            assert_eq!(original, single);
+        }
+    }
+}
+
+/// `len` passports, each with every field, and each field wrong about one time in ten.
+fn generate(len: usize) -> String {
+    let mut lcg = Lcg::new(0x2020);
+    let mut next = |n: u64| lcg.below(n);
+
+    let mut input = String::with_capacity(len * 110);
+    for i in 0..len {
+        if i > 0 {
+            input.push_str("\n\n");
+        }
+        let wrong = |next: &mut dyn FnMut(u64) -> u64| next(10) == 0;
+        let byr = if wrong(&mut next) {
+            1900
+        } else {
+            1920 + next(83)
+        };
+        let hgt = if wrong(&mut next) {
+            format!("{}in", 100 + next(50))
+        } else {
+            format!("{}cm", 150 + next(44))
+        };
+        let hcl = if wrong(&mut next) {
+            format!("{:06x}", next(1 << 24))
+        } else {
+            format!("#{:06x}", next(1 << 24))
+        };
+        let ecl = if wrong(&mut next) { "pnk" } else { "brn" };
+        let pid = if wrong(&mut next) {
+            format!("{:08}", next(100_000_000))
+        } else {
+            format!("{:09}", next(1_000_000_000))
+        };
+        input.push_str(&format!(
+            "byr:{} iyr:{} eyr:{} hgt:{}\nhcl:{} ecl:{} pid:{} cid:{}",
+            byr,
+            2010 + next(11),
+            2020 + next(11),
+            hgt,
+            hcl,
+            ecl,
+            pid,
+            next(1000)
+        ));
+    }
+    input
+}
+
+/// How part two was originally checked: ranges compared by hand, and each regex compiled every
+/// time it was used.
+fn original_is_valid(passport: &Passport) -> bool {
+    let year = |key: &str, min: usize, max: usize| {
+        passport
+            .get(key)
+            .and_then(|value| value.parse::<usize>().ok())
+            .is_some_and(|value| min <= value && value <= max)
+    };
+    let height = passport.get("hgt").is_some_and(|h| {
+        let len = h.len();
+        (4..=5).contains(&len)
+            && match &h[(len - 2)..] {
+                "in" => len == 4 && ("59in"..="76in").contains(&h),
+                "cm" => len == 5 && ("150cm"..="193cm").contains(&h),
+                _ => false,
+            }
+    });
+    let regex = |key: &str, pattern: &str| {
+        passport
+            .get(key)
+            .is_some_and(|value| regex::Regex::new(pattern).unwrap().is_match(value))
+    };
+    let eye_color = passport
+        .get("ecl")
+        .is_some_and(|e| matches!(e, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"));
+
+    year("byr", 1920, 2002)
+        && year("iyr", 2010, 2020)
+        && year("eyr", 2020, 2030)
+        && height
+        && regex("hcl", "^#[0-9a-f]{6}$")
+        && eye_color
+        && regex("pid", "^[0-9]{9}$")
+}
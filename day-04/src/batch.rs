@@ -0,0 +1,146 @@
+//! Validating batches of passports too big to comfortably keep in memory, across threads.
+
+use crate::{Passport, Schema, UnknownKeys};
+use aoc_core::ParseError;
+
+/// How many passports in a batch there were, and how many passed each part's checks.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, serde::Serialize)]
+pub struct BatchSummary {
+    pub passports: usize,
+    /// Passports with every required field.
+    pub complete: usize,
+    /// Passports whose fields all follow the schema.
+    pub valid: usize,
+}
+
+impl std::ops::Add for BatchSummary {
+    type Output = BatchSummary;
+
+    fn add(self, other: BatchSummary) -> BatchSummary {
+        BatchSummary {
+            passports: self.passports + other.passports,
+            complete: self.complete + other.complete,
+            valid: self.valid + other.valid,
+        }
+    }
+}
+
+/// Parse and validate the blank-line separated passports in `input` on `threads` threads.
+///
+/// Each passport is counted and then dropped, so memory doesn't grow with the number of
+/// passports. If any passport doesn't parse, the error is for the first one in the input.
+pub fn validate_batch(
+    input: &str,
+    schema: &Schema,
+    unknown_keys: UnknownKeys,
+    threads: usize,
+) -> Result<BatchSummary, ParseError> {
+    let chunks = chunks(input, threads.max(1));
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| scope.spawn(move || validate_chunk(input, chunk, schema, unknown_keys)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("a validating thread panicked"))
+            .try_fold(BatchSummary::default(), |total, summary| {
+                Ok(total + summary?)
+            })
+    })
+}
+
+fn validate_chunk(
+    input: &str,
+    chunk: &str,
+    schema: &Schema,
+    unknown_keys: UnknownKeys,
+) -> Result<BatchSummary, ParseError> {
+    let mut summary = BatchSummary::default();
+    for block in chunk.split("\n\n") {
+        let passport = Passport::parse_with(block, unknown_keys)
+            .map_err(|err| ParseError::at(input, &block[err.offset()..], err))?;
+        summary.passports += 1;
+        if schema.is_complete(&passport) {
+            summary.complete += 1;
+        }
+        if schema.is_valid(&passport) {
+            summary.valid += 1;
+        }
+    }
+    Ok(summary)
+}
+
+/// Split the input into about `n` similarly sized chunks of whole passports.
+///
+/// Chunks end where a passport ends, so splitting a chunk on blank lines gives exactly the
+/// passports that splitting the whole input would, even around runs of several blank lines.
+fn chunks(input: &str, n: usize) -> Vec<&str> {
+    let size = input.len() / n + 1;
+    let mut chunks = Vec::with_capacity(n);
+    let mut start = 0;
+    for block in input.split("\n\n") {
+        let end = block.as_ptr() as usize - input.as_ptr() as usize + block.len();
+        if end - start >= size {
+            chunks.push(&input[start..end]);
+            // Skip the blank line; the next passport starts after it.
+            start = end + 2;
+        }
+    }
+    if start <= input.len() {
+        chunks.push(&input[start..]);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+    #[test]
+    fn same_passports_as_splitting_the_whole_input() {
+        let whole: Vec<&str> = EXAMPLE.split("\n\n").collect();
+        for n in 1..=EXAMPLE.len() + 1 {
+            let chunked: Vec<&str> = chunks(EXAMPLE, n)
+                .into_iter()
+                .flat_map(|chunk| chunk.split("\n\n"))
+                .collect();
+            assert_eq!(chunked, whole, "{} chunks", n);
+        }
+    }
+
+    #[test]
+    fn batch() {
+        let summary = validate_batch(EXAMPLE, Schema::puzzle(), UnknownKeys::Strict, 3).unwrap();
+        assert_eq!(
+            summary,
+            BatchSummary {
+                passports: 4,
+                complete: 2,
+                valid: 2
+            }
+        );
+
+        let input = format!("{}\nxyz:1\n", EXAMPLE);
+        let err = validate_batch(&input, Schema::puzzle(), UnknownKeys::Strict, 3).unwrap_err();
+        assert_eq!(err.line(), 16);
+        assert!(validate_batch(&input, Schema::puzzle(), UnknownKeys::Lenient, 3).is_ok());
+    }
+}
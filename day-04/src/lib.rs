@@ -0,0 +1,318 @@
+mod batch;
+mod schema;
+
+pub use batch::*;
+pub use schema::*;
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+
+pub struct Day04 {
+    passports: Vec<Passport>,
+    schema: Schema,
+}
+
+impl Day04 {
+    /// Validate against rules other than the puzzle's.
+    pub fn with_schema(self, schema: Schema) -> Self {
+        Day04 { schema, ..self }
+    }
+
+    /// Parse the passports, deciding what to do about keys that aren't in `KEYS`.
+    pub fn parse_with(input: &str, unknown_keys: UnknownKeys) -> Result<Self, ParseError> {
+        let passports = input
+            .split("\n\n")
+            .map(|block| {
+                Passport::parse_with(block, unknown_keys)
+                    .map_err(|err| ParseError::at(input, &block[err.offset()..], err))
+            })
+            .collect::<Result<Vec<Passport>, _>>()?;
+        Ok(Day04 {
+            passports,
+            schema: Schema::puzzle().clone(),
+        })
+    }
+
+    /// Everything wrong with each passport, in order.
+    pub fn violations(&self) -> Vec<Vec<Violation>> {
+        self.passports
+            .iter()
+            .map(|passport| self.schema.validate(passport))
+            .collect()
+    }
+}
+
+impl Solution<'_> for Day04 {
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day04::parse_with(input, UnknownKeys::Strict)
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let valid = self
+            .passports
+            .iter()
+            .filter(|passport| self.schema.is_complete(passport))
+            .count();
+        Ok(valid.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let valid = self
+            .passports
+            .iter()
+            .filter(|passport| self.schema.is_valid(passport))
+            .count();
+        Ok(valid.into())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Passport {
+    pub birth_year: Option<Year>,
+    pub issue_year: Option<Year>,
+    pub expiration_year: Option<Year>,
+    pub height: Option<Height>,
+    pub hair_color: Option<HairColor>,
+    pub eye_color: Option<EyeColor>,
+    pub passport_id: Option<PassportId>,
+    pub country_id: Option<CountryId>,
+}
+
+/// Every key a passport can have.
+pub const KEYS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+impl Passport {
+    /// The value of a field by its key, such as `byr`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        match key {
+            "byr" => self.birth_year.as_ref().map(|f| f.0.as_str()),
+            "iyr" => self.issue_year.as_ref().map(|f| f.0.as_str()),
+            "eyr" => self.expiration_year.as_ref().map(|f| f.0.as_str()),
+            "hgt" => self.height.as_ref().map(|f| f.0.as_str()),
+            "hcl" => self.hair_color.as_ref().map(|f| f.0.as_str()),
+            "ecl" => self.eye_color.as_ref().map(|f| f.0.as_str()),
+            "pid" => self.passport_id.as_ref().map(|f| f.0.as_str()),
+            "cid" => self.country_id.as_ref().map(|f| f.0.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Has every field the puzzle requires.
+    pub fn is_valid(&self) -> bool {
+        Schema::puzzle().is_complete(self)
+    }
+
+    /// Has every field the puzzle requires, and they all follow the puzzle's rules.
+    pub fn is_valid_2(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    /// Everything wrong with the passport according to the puzzle's rules.
+    pub fn validate(&self) -> Vec<Violation> {
+        Schema::puzzle().validate(self)
+    }
+}
+
+/// Whether a key that isn't in `KEYS` is an error, or is skipped.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum UnknownKeys {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+impl std::str::FromStr for UnknownKeys {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(UnknownKeys::Strict),
+            "lenient" => Ok(UnknownKeys::Lenient),
+            _ => Err(format!("Unknown mode '{}', expected strict or lenient", s)),
+        }
+    }
+}
+
+/// A problem with a passport's text. Each has the byte offset of the problem within the text.
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+pub enum PassportParseError {
+    #[error("Invalid key: {key}")]
+    InvalidKey { key: String, offset: usize },
+    #[error("Missing colon between key and value")]
+    MissingColon { offset: usize },
+    #[error("Key {key} is given more than once")]
+    DuplicateKey { key: String, offset: usize },
+    #[error("Key {key} has no value")]
+    EmptyValue { key: String, offset: usize },
+    #[error("Key {key:?} isn't ASCII")]
+    NonAsciiKey { key: String, offset: usize },
+}
+
+impl PassportParseError {
+    /// The byte offset of the problem within the passport's text.
+    pub fn offset(&self) -> usize {
+        match self {
+            PassportParseError::InvalidKey { offset, .. }
+            | PassportParseError::MissingColon { offset }
+            | PassportParseError::DuplicateKey { offset, .. }
+            | PassportParseError::EmptyValue { offset, .. }
+            | PassportParseError::NonAsciiKey { offset, .. } => *offset,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Year(String);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Height(String);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HairColor(String);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EyeColor(String);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PassportId(String);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CountryId(String);
+
+impl Passport {
+    /// Parse `key:value` pairs separated by whitespace, deciding what to do about keys that
+    /// aren't in `KEYS`.
+    pub fn parse_with(s: &str, unknown_keys: UnknownKeys) -> Result<Self, PassportParseError> {
+        let mut passport = Passport::default();
+        let mut seen: Vec<&str> = Vec::new();
+
+        for part in s.split_whitespace() {
+            let offset = part.as_ptr() as usize - s.as_ptr() as usize;
+            let colon = part
+                .find(':')
+                .ok_or(PassportParseError::MissingColon { offset })?;
+            let (key, value) = (&part[..colon], &part[(colon + 1)..]);
+
+            if !key.is_ascii() {
+                return Err(PassportParseError::NonAsciiKey {
+                    key: key.to_string(),
+                    offset,
+                });
+            }
+            if value.is_empty() {
+                return Err(PassportParseError::EmptyValue {
+                    key: key.to_string(),
+                    offset: offset + colon + 1,
+                });
+            }
+            if seen.contains(&key) {
+                return Err(PassportParseError::DuplicateKey {
+                    key: key.to_string(),
+                    offset,
+                });
+            }
+            seen.push(key);
+
+            match key {
+                "byr" => passport.birth_year = Some(Year(value.to_string())),
+                "iyr" => passport.issue_year = Some(Year(value.to_string())),
+                "eyr" => passport.expiration_year = Some(Year(value.to_string())),
+                "hgt" => passport.height = Some(Height(value.to_string())),
+                "hcl" => passport.hair_color = Some(HairColor(value.to_string())),
+                "ecl" => passport.eye_color = Some(EyeColor(value.to_string())),
+                "pid" => passport.passport_id = Some(PassportId(value.to_string())),
+                "cid" => passport.country_id = Some(CountryId(value.to_string())),
+                // An empty key isn't unknown so much as missing, so it's never skipped.
+                _ if unknown_keys == UnknownKeys::Lenient && !key.is_empty() => {}
+                _ => {
+                    return Err(PassportParseError::InvalidKey {
+                        key: key.to_string(),
+                        offset,
+                    })
+                }
+            }
+        }
+
+        Ok(passport)
+    }
+}
+
+impl std::str::FromStr for Passport {
+    type Err = PassportParseError;
+
+    /// Parse strictly, so unknown keys are errors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Passport::parse_with(s, UnknownKeys::Strict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse1() {
+        let s = r#"ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm"#;
+
+        assert_eq!(
+            s.parse(),
+            Ok(Passport {
+                eye_color: Some(EyeColor("gry".to_string())),
+                passport_id: Some(PassportId("860033327".to_string())),
+                expiration_year: Some(Year("2020".to_string())),
+                hair_color: Some(HairColor("#fffffd".to_string())),
+                birth_year: Some(Year("1937".to_string())),
+                issue_year: Some(Year("2017".to_string())),
+                country_id: Some(CountryId("147".to_string())),
+                height: Some(Height("183cm".to_string())),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let error = |s: &str| s.parse::<Passport>().unwrap_err();
+        assert_eq!(
+            error("ecl:gry pid"),
+            PassportParseError::MissingColon { offset: 8 }
+        );
+        assert_eq!(
+            error("ecl:gry ecl:blu"),
+            PassportParseError::DuplicateKey {
+                key: "ecl".to_string(),
+                offset: 8
+            }
+        );
+        assert_eq!(
+            error("ecl:gry\npid:"),
+            PassportParseError::EmptyValue {
+                key: "pid".to_string(),
+                offset: 12
+            }
+        );
+        assert_eq!(
+            error("écl:gry"),
+            PassportParseError::NonAsciiKey {
+                key: "écl".to_string(),
+                offset: 0
+            }
+        );
+        assert_eq!(
+            error("ecl:gry xyz:1"),
+            PassportParseError::InvalidKey {
+                key: "xyz".to_string(),
+                offset: 8
+            }
+        );
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let passport = Passport::parse_with("xyz:1 ecl:gry", UnknownKeys::Lenient).unwrap();
+        assert_eq!(passport.get("ecl"), Some("gry"));
+        assert!(Passport::parse_with(":1", UnknownKeys::Lenient).is_err());
+        assert!(Passport::parse_with("xyz:1 xyz:2", UnknownKeys::Lenient).is_err());
+    }
+}
@@ -0,0 +1,366 @@
+//! The rules a passport's fields have to follow, loaded from JSON so they can change without a
+//! recompile. The puzzle's rules are in `schema.json`.
+
+use crate::{Passport, KEYS};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+/// The rule for one key. A field without a rule can have any value.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Field {
+    pub key: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub rule: Option<Rule>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Rule {
+    /// A number, written in digits, within a range.
+    Range(Range),
+    /// A number followed by one of the units, within that unit's range, such as `183cm`.
+    Units(BTreeMap<String, Range>),
+    /// Matches a regex.
+    Pattern(#[serde(deserialize_with = "regex")] regex::Regex),
+    /// One of a list of values.
+    OneOf(Vec<String>),
+}
+
+/// An inclusive range.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize)]
+pub struct Range {
+    pub min: u64,
+    pub max: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("Invalid schema: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Unknown field '{0}'")]
+    UnknownField(String),
+    #[error("Field '{0}' is given more than once")]
+    DuplicateField(String),
+}
+
+impl Schema {
+    /// The puzzle's rules. They're loaded, and their regexes compiled, the first time they're
+    /// needed, and shared after that.
+    pub fn puzzle() -> &'static Schema {
+        static PUZZLE: OnceLock<Schema> = OnceLock::new();
+        PUZZLE.get_or_init(|| {
+            Schema::from_json(include_str!("../schema.json")).expect("schema.json is valid")
+        })
+    }
+
+    pub fn from_json(json: &str) -> Result<Schema, SchemaError> {
+        let schema: Schema = serde_json::from_str(json)?;
+        for (i, field) in schema.fields.iter().enumerate() {
+            if !KEYS.contains(&field.key.as_str()) {
+                return Err(SchemaError::UnknownField(field.key.clone()));
+            }
+            if schema.fields[..i].iter().any(|f| f.key == field.key) {
+                return Err(SchemaError::DuplicateField(field.key.clone()));
+            }
+        }
+        Ok(schema)
+    }
+
+    /// Whether the passport has every required field, whatever their values.
+    pub fn is_complete(&self, passport: &Passport) -> bool {
+        self.fields
+            .iter()
+            .all(|field| !field.required || passport.get(&field.key).is_some())
+    }
+
+    /// Whether the passport has every required field, and every field it has follows its rule.
+    pub fn is_valid(&self, passport: &Passport) -> bool {
+        self.validate(passport).is_empty()
+    }
+
+    /// Everything wrong with the passport: each missing required field, and each field that
+    /// breaks its rule.
+    pub fn validate(&self, passport: &Passport) -> Vec<Violation> {
+        self.fields
+            .iter()
+            .filter_map(|field| match passport.get(&field.key) {
+                Some(value) => field.rule.as_ref()?.check(&field.key, value),
+                None if field.required => Some(Violation::Missing {
+                    key: field.key.clone(),
+                }),
+                None => None,
+            })
+            .collect()
+    }
+
+    /// Whether a value follows the rule for its key. Keys without a rule accept anything.
+    pub fn check(&self, key: &str, value: &str) -> bool {
+        self.fields
+            .iter()
+            .find(|field| field.key == key)
+            .and_then(|field| field.rule.as_ref())
+            .is_none_or(|rule| rule.matches(value))
+    }
+}
+
+impl Rule {
+    pub fn matches(&self, value: &str) -> bool {
+        self.check("", value).is_none()
+    }
+
+    /// How the value breaks the rule, if it does.
+    pub fn check(&self, key: &str, value: &str) -> Option<Violation> {
+        let violation = |kind| Violation::Invalid {
+            key: key.to_string(),
+            value: value.to_string(),
+            kind,
+        };
+        match self {
+            Rule::Range(range) => range.check(value).map(violation),
+            Rule::Units(units) => {
+                // Units can end with other units, like `min` and `in`, so take the longest.
+                let found = units
+                    .iter()
+                    .filter_map(|(unit, range)| {
+                        Some((value.strip_suffix(unit.as_str())?, unit, range))
+                    })
+                    .max_by_key(|(_, unit, _)| unit.len());
+                match found {
+                    Some((number, unit, range)) => range.check(number).map(|kind| match kind {
+                        ViolationKind::OutOfRange { min, max, .. } => {
+                            violation(ViolationKind::OutOfRange {
+                                min,
+                                max,
+                                unit: Some(unit.clone()),
+                            })
+                        }
+                        kind => violation(kind),
+                    }),
+                    None => Some(violation(ViolationKind::NoUnit(
+                        units.keys().cloned().collect(),
+                    ))),
+                }
+            }
+            Rule::Pattern(regex) if !regex.is_match(value) => Some(violation(
+                ViolationKind::NoMatch(regex.as_str().to_string()),
+            )),
+            Rule::Pattern(_) => None,
+            Rule::OneOf(values) if !values.iter().any(|v| v == value) => {
+                Some(violation(ViolationKind::NotAllowed))
+            }
+            Rule::OneOf(_) => None,
+        }
+    }
+}
+
+impl Range {
+    /// Whether the text is a number in the range. Only digits count, so not `+2000` or `2e3`.
+    pub fn contains(&self, number: &str) -> bool {
+        self.check(number).is_none()
+    }
+
+    fn check(&self, number: &str) -> Option<ViolationKind> {
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Some(ViolationKind::NotANumber);
+        }
+        match number.parse::<u64>() {
+            Ok(n) if self.min <= n && n <= self.max => None,
+            _ => Some(ViolationKind::OutOfRange {
+                min: self.min,
+                max: self.max,
+                unit: None,
+            }),
+        }
+    }
+}
+
+/// Something wrong with one field of a passport.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "violation", rename_all = "kebab-case")]
+pub enum Violation {
+    Missing {
+        key: String,
+    },
+    Invalid {
+        key: String,
+        value: String,
+        kind: ViolationKind,
+    },
+}
+
+/// How a value breaks its field's rule.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViolationKind {
+    NotANumber,
+    OutOfRange {
+        min: u64,
+        max: u64,
+        unit: Option<String>,
+    },
+    /// None of the units the value could have.
+    NoUnit(Vec<String>),
+    /// The pattern the value doesn't match.
+    NoMatch(String),
+    NotAllowed,
+}
+
+impl Violation {
+    /// The violation without the offending value, such as `hgt is outside 150-193cm`, for
+    /// counting how often each kind of problem happens.
+    pub fn summary(&self) -> String {
+        match self {
+            Violation::Missing { key } => format!("missing {}", key),
+            Violation::Invalid { key, kind, .. } => format!("{} {}", key, kind),
+        }
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Missing { key } => write!(f, "missing {}", key),
+            Violation::Invalid { key, value, kind } => write!(f, "{} {:?} {}", key, value, kind),
+        }
+    }
+}
+
+impl std::fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViolationKind::NotANumber => write!(f, "is not a number"),
+            ViolationKind::OutOfRange { min, max, unit } => write!(
+                f,
+                "is outside {}-{}{}",
+                min,
+                max,
+                unit.as_deref().unwrap_or("")
+            ),
+            ViolationKind::NoUnit(units) => {
+                write!(f, "has no unit, expected {}", units.join(" or "))
+            }
+            ViolationKind::NoMatch(pattern) => write!(f, "doesn't match {}", pattern),
+            ViolationKind::NotAllowed => write!(f, "is not an allowed value"),
+        }
+    }
+}
+
+fn regex<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<regex::Regex, D::Error> {
+    let pattern = String::deserialize(deserializer)?;
+    regex::Regex::new(&pattern).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_year() {
+        let schema = Schema::puzzle();
+        assert!(schema.check("byr", "2002"));
+        assert!(!schema.check("byr", "2003"));
+        assert!(!schema.check("byr", "02"));
+        assert!(!schema.check("byr", "+2002"));
+    }
+
+    #[test]
+    fn valid_height() {
+        let schema = Schema::puzzle();
+        assert!(schema.check("hgt", "60in"));
+        assert!(schema.check("hgt", "190cm"));
+        assert!(!schema.check("hgt", "190in"));
+        assert!(!schema.check("hgt", "190"));
+        // Compared as numbers, not as text.
+        assert!(!schema.check("hgt", "1500cm"));
+        assert!(!schema.check("hgt", "2in"));
+    }
+
+    #[test]
+    fn longest_unit() {
+        let schema = Schema::from_json(
+            r#"{ "fields": [{ "key": "hgt", "rule": { "units": {
+                "in": { "min": 1, "max": 2 },
+                "min": { "min": 5, "max": 10 }
+            } } }] }"#,
+        )
+        .unwrap();
+        assert!(schema.check("hgt", "5min"));
+        assert!(schema.check("hgt", "2in"));
+        assert!(!schema.check("hgt", "5in"));
+    }
+
+    #[test]
+    fn valid_hair_color() {
+        let schema = Schema::puzzle();
+        assert!(schema.check("hcl", "#123abc"));
+        assert!(!schema.check("hcl", "#123abz"));
+        assert!(!schema.check("hcl", "123abc"));
+    }
+
+    #[test]
+    fn valid_passport_id() {
+        let schema = Schema::puzzle();
+        assert!(schema.check("pid", "000000001"));
+        assert!(!schema.check("pid", "0123456789"));
+    }
+
+    #[test]
+    fn violations() {
+        let passport: Passport = "byr:1919 iyr:2015 eyr:x hgt:200cm hcl:123abc ecl:pnk"
+            .parse()
+            .unwrap();
+        let violations = Schema::puzzle().validate(&passport);
+        let summaries: Vec<String> = violations.iter().map(Violation::summary).collect();
+        assert_eq!(
+            summaries,
+            vec![
+                "byr is outside 1920-2002",
+                "eyr is not a number",
+                "hgt is outside 150-193cm",
+                "hcl doesn't match ^#[0-9a-f]{6}$",
+                "ecl is not an allowed value",
+                "missing pid",
+            ]
+        );
+        assert_eq!(
+            violations[2].to_string(),
+            r#"hgt "200cm" is outside 150-193cm"#
+        );
+
+        let passport: Passport = "hgt:190".parse().unwrap();
+        assert_eq!(
+            Schema::puzzle().validate(&passport)[3].summary(),
+            "hgt has no unit, expected cm or in"
+        );
+    }
+
+    #[test]
+    fn load() {
+        let schema = Schema::from_json(
+            r#"{ "fields": [{ "key": "ecl", "required": true, "rule": { "one-of": ["pnk"] } }] }"#,
+        )
+        .unwrap();
+        assert!(schema.check("ecl", "pnk"));
+        assert!(!schema.check("ecl", "blu"));
+        let passport: Passport = "ecl:pnk".parse().unwrap();
+        assert!(schema.is_valid(&passport));
+        assert!(!Schema::puzzle().is_valid(&passport));
+
+        assert!(Schema::from_json(r#"{ "fields": [{ "key": "xyz" }] }"#).is_err());
+        assert!(
+            Schema::from_json(r#"{ "fields": [{ "key": "byr" }, { "key": "byr" }] }"#).is_err()
+        );
+        assert!(Schema::from_json(
+            r#"{ "fields": [{ "key": "hcl", "rule": { "pattern": "[" } }] }"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,321 @@
+mod simulator;
+
+pub use simulator::*;
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+
+pub struct Day05 {
+    /// Sorted by seat ID.
+    seats: Vec<Seat>,
+    layout: Layout,
+}
+
+impl Day05 {
+    /// Parse boarding passes for a plane with a different number of rows or columns.
+    pub fn parse_with(input: &str, layout: Layout) -> Result<Self, ParseError> {
+        let mut seats = input
+            .trim()
+            .split('\n')
+            .map(|line| Seat::parse_with(line, layout).map_err(|err| err.within(input, line)))
+            .collect::<Result<Vec<Seat>, _>>()?;
+        seats.sort();
+        Ok(Day05 { seats, layout })
+    }
+
+    /// The seat map of every boarding pass.
+    pub fn seat_map(&self) -> String {
+        seat_map(&self.seats, self.layout)
+    }
+}
+
+impl Solution<'_> for Day05 {
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day05::parse_with(input, Layout::default())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let seat = self.seats.last().ok_or("No seats")?;
+        Ok(seat.id().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let seat_id = missing_seats(&self.seats)
+            .into_iter()
+            .next()
+            .ok_or("No missing seat")?;
+        Ok(seat_id.into())
+    }
+}
+
+/// Seat IDs that are skipped over in an already-sorted list of seats.
+pub fn missing_seats(seats: &[Seat]) -> Vec<u32> {
+    let mut missing = Vec::new();
+    let mut next_seat_id_expected = match seats.first() {
+        Some(seat) => seat.id(),
+        None => return missing,
+    };
+    for seat in seats {
+        missing.extend(next_seat_id_expected..seat.id());
+        next_seat_id_expected = seat.id() + 1;
+    }
+    missing
+}
+
+/// The shape of a plane: how many bits of a boarding pass pick the row, and how many pick the
+/// column. The puzzle's plane has 7 and 3, for 128 rows of 8 seats.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Layout {
+    row_bits: u32,
+    column_bits: u32,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout {
+            row_bits: 7,
+            column_bits: 3,
+        }
+    }
+}
+
+impl Layout {
+    /// Seat IDs have to fit in a `u32`, so there can be at most 31 bits between them.
+    pub fn new(row_bits: u32, column_bits: u32) -> Result<Layout> {
+        let fits = row_bits
+            .checked_add(column_bits)
+            .filter(|&bits| bits <= 31)
+            .is_some();
+        if !fits {
+            return Err(format!(
+                "{} row bits and {} column bits is more than 31 bits",
+                row_bits, column_bits
+            )
+            .into());
+        }
+        Ok(Layout {
+            row_bits,
+            column_bits,
+        })
+    }
+
+    pub fn rows(&self) -> u32 {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> u32 {
+        1 << self.column_bits
+    }
+
+    /// The first column after the aisle down the middle of the plane.
+    pub fn aisle(&self) -> u32 {
+        self.columns() / 2
+    }
+
+    /// How long a boarding pass is.
+    pub fn pass_len(&self) -> usize {
+        (self.row_bits + self.column_bits) as usize
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Seat {
+    id: u32,
+    layout: Layout,
+}
+
+impl Seat {
+    /// The seat at a row and column, if the plane has them.
+    pub fn new(row: u32, column: u32, layout: Layout) -> Option<Seat> {
+        if row >= layout.rows() || column >= layout.columns() {
+            return None;
+        }
+        Some(Seat {
+            id: (row << layout.column_bits) | column,
+            layout,
+        })
+    }
+
+    /// Parse a boarding pass for a plane with a different number of rows or columns. The row
+    /// has to be all `F` and `B`, and the column all `L` and `R`.
+    pub fn parse_with(s: &str, layout: Layout) -> Result<Self, ParseError> {
+        let found = s.chars().count();
+        if found != layout.pass_len() {
+            return Err(ParseError::at(
+                s,
+                s,
+                SeatParseError::WrongLength {
+                    expected: layout.pass_len(),
+                    found,
+                },
+            ));
+        }
+
+        let mut val = 0;
+        for (i, (offset, ch)) in s.char_indices().enumerate() {
+            let bit = match (i < layout.row_bits as usize, ch) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                _ => {
+                    return Err(ParseError::at(
+                        s,
+                        &s[offset..],
+                        SeatParseError::InvalidCharacter(ch),
+                    ))
+                }
+            };
+            val = (val << 1) | bit;
+        }
+        Ok(Seat { id: val, layout })
+    }
+
+    pub fn row(&self) -> u32 {
+        self.id >> self.layout.column_bits
+    }
+
+    pub fn column(&self) -> u32 {
+        self.id & (self.layout.columns() - 1)
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The plane the seat is on.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+}
+
+impl std::fmt::Display for Seat {
+    /// The seat's boarding pass.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for bit in (0..self.layout.row_bits).rev() {
+            let back = self.row() & (1 << bit) != 0;
+            write!(f, "{}", if back { 'B' } else { 'F' })?;
+        }
+        for bit in (0..self.layout.column_bits).rev() {
+            let right = self.column() & (1 << bit) != 0;
+            write!(f, "{}", if right { 'R' } else { 'L' })?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum SeatParseError {
+    #[error("Invalid character: {0}")]
+    InvalidCharacter(char),
+    #[error("Boarding pass is {found} characters long, expected {expected}")]
+    WrongLength { expected: usize, found: usize },
+}
+
+impl std::str::FromStr for Seat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Seat::parse_with(s, Layout::default())
+    }
+}
+
+/// A map of the plane, a row to a line, with an aisle down the middle: `#` for a seat with a
+/// boarding pass, `O` for the gaps `missing_seats` finds, and `.` for the empty seats before the
+/// first boarding pass or after the last.
+pub fn seat_map(seats: &[Seat], layout: Layout) -> String {
+    let mut occupied = vec![false; (layout.rows() * layout.columns()) as usize];
+    for seat in seats {
+        occupied[seat.id() as usize] = true;
+    }
+    let mut gaps = vec![false; occupied.len()];
+    for id in missing_seats(seats) {
+        gaps[id as usize] = true;
+    }
+
+    let row_width = (layout.rows() - 1).to_string().len();
+    let mut map = String::new();
+    for row in 0..layout.rows() {
+        map.push_str(&format!("{:>width$} ", row, width = row_width));
+        for column in 0..layout.columns() {
+            if column == layout.aisle() && column > 0 {
+                map.push(' ');
+            }
+            let id = ((row << layout.column_bits) | column) as usize;
+            map.push(match (occupied[id], gaps[id]) {
+                (true, _) => '#',
+                (false, true) => 'O',
+                (false, false) => '.',
+            });
+        }
+        map.push('\n');
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let seat: Seat = "FBFBBFFRLR".parse().unwrap();
+        assert_eq!(seat.row(), 44);
+        assert_eq!(seat.column(), 5);
+        assert_eq!(seat.id(), 357);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let err = "FBFBXFFRLR".parse::<Seat>().unwrap_err();
+        assert_eq!(err.column(), 5);
+        assert_eq!(
+            err.kind::<SeatParseError>(),
+            Some(&SeatParseError::InvalidCharacter('X'))
+        );
+    }
+
+    #[test]
+    fn parse_wrong_length() {
+        for pass in &["FBFBBFFRL", "FBFBBFFRLRR", ""] {
+            let err = pass.parse::<Seat>().unwrap_err();
+            assert!(matches!(
+                err.kind::<SeatParseError>(),
+                Some(SeatParseError::WrongLength { expected: 10, .. })
+            ));
+        }
+        // Row letters in the column, and the other way around.
+        assert!("FBFBBFFRLF".parse::<Seat>().is_err());
+        assert!("FBFBBFRRLR".parse::<Seat>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for pass in &["FBFBBFFRLR", "BFFFBBFRRR", "FFFBBBFRRR", "BBFFBBFRLL"] {
+            let seat: Seat = pass.parse().unwrap();
+            assert_eq!(seat.to_string(), *pass);
+        }
+
+        let layout = Layout::new(3, 2).unwrap();
+        let seat = Seat::parse_with("BFBRL", layout).unwrap();
+        assert_eq!((seat.row(), seat.column(), seat.id()), (5, 2, 22));
+        assert_eq!(seat.to_string(), "BFBRL");
+        assert_eq!(Seat::new(5, 2, layout), Some(seat));
+        assert_eq!(Seat::new(8, 0, layout), None);
+        assert!(Layout::new(20, 12).is_err());
+        assert!(Layout::new(u32::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn map() {
+        let layout = Layout::new(2, 2).unwrap();
+        let mut seats: Vec<Seat> = ["FBLR", "FBRL", "BFLL", "BFRR"]
+            .iter()
+            .map(|pass| Seat::parse_with(pass, layout).unwrap())
+            .collect();
+        seats.sort();
+        assert_eq!(
+            seat_map(&seats, layout),
+            "0 .. ..\n1 .# #O\n2 #O O#\n3 .. ..\n"
+        );
+    }
+}
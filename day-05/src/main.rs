@@ -1,82 +1,147 @@
-use std::io::Read;
+use aoc_core::bench::Timings;
+use aoc_core::cli::{Cli, Format};
+use aoc_core::Report;
+use day_05::{Day05, Layout, Plane, Seat, SeatParseError, Simulator};
+use std::io::BufRead;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input).unwrap();
-    let mut seats = input
-        .trim()
-        .split('\n')
-        .map(|line| line.parse())
-        .collect::<Result<Vec<Seat>, _>>()
-        .unwrap();
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
 
-    seats.sort();
+fn main() -> Result<()> {
+    let cli = Cli::parse(&[
+        ("row-bits", "N"),
+        ("column-bits", "N"),
+        ("map", ""),
+        ("simulate", ""),
+        ("blocked-front", "N"),
+        ("blocked-back", "N"),
+        ("party", "N"),
+    ]);
+    let default = Layout::default();
+    let layout = Layout::new(
+        cli.flag_or("row-bits", default.rows().trailing_zeros())?,
+        cli.flag_or("column-bits", default.columns().trailing_zeros())?,
+    )?;
 
-    println!("{}", seats.last().unwrap().id());
-
-    let mut next_seat_id_expected = seats.first().unwrap().id();
-    for seat in seats {
-        if seat.id() != next_seat_id_expected {
-            println!("{}", next_seat_id_expected);
-        }
-        next_seat_id_expected = seat.id() + 1;
+    if cli.switch("simulate") {
+        let plane = Plane {
+            layout,
+            blocked_front: cli.flag_or("blocked-front", 0)?,
+            blocked_back: cli.flag_or("blocked-back", 0)?,
+        };
+        return simulate(&cli, plane, cli.flag_or("party", 2)?);
     }
-}
-
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
-struct Seat(u32);
 
-impl Seat {
-    fn row(&self) -> u32 {
-        self.0 >> 3
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
+    let (day, mut report) = Report::solve_parsed(&input, |input| Day05::parse_with(input, layout))?;
+    if !cli.switch("map") {
+        return report.print(cli.format());
     }
 
-    fn column(&self) -> u32 {
-        self.0 & 7
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            print!("{}", day.seat_map());
+        }
+        Format::Json => {
+            let map: Vec<String> = day.seat_map().lines().map(str::to_string).collect();
+            report.extra("map", map)?;
+            report.print(Format::Json)?;
+        }
     }
 
-    fn id(&self) -> u32 {
-        self.0
-    }
+    Ok(())
 }
 
-impl std::str::FromStr for Seat {
-    type Err = ();
+/// Board passengers a boarding pass at a time, then report how the plane filled up. Boarding
+/// passes that can't be used are reported, rather than stopping everything.
+fn simulate(cli: &Cli, plane: Plane, party: usize) -> Result<()> {
+    let start = Instant::now();
+    let mut simulator = Simulator::new(plane);
+    let mut rejected = Vec::new();
+    let reader = cli.open_input(Some(include_str!("../input.txt")))?;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let pass = line.trim();
+        if pass.is_empty() {
+            continue;
+        }
+        let boarded = Seat::parse_with(pass, plane.layout)
+            .map_err(|err| {
+                let kind = err.kind::<SeatParseError>().expect("a seat parse error");
+                format!("column {}: {}", err.column(), kind)
+            })
+            .and_then(|seat| simulator.board(seat).map_err(|err| err.to_string()));
+        if let Err(err) = boarded {
+            rejected.push((i + 1, pass.to_string(), err));
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut val = 0;
-        for ch in s.chars() {
-            match ch {
-                'F' => {
-                    val <<= 1;
-                }
-                'B' => {
-                    val <<= 1;
-                    val += 1;
-                }
-                'L' => {
-                    val <<= 1;
-                }
-                'R' => {
-                    val <<= 1;
-                    val += 1;
-                }
-                _ => {}
+    let empty = simulator.empty_seats();
+    let options = simulator.seats_for_party(party);
+    let occupancy = simulator.occupancy();
+    // Boarding doesn't answer either part, so all of the time goes to reading the passes.
+    let timings = Timings::once(start.elapsed(), Duration::ZERO, Duration::ZERO);
+    let mut report = Report::new(5, None, None, timings);
+    match cli.format() {
+        Format::Text => {
+            for (line, pass, err) in &rejected {
+                println!("rejected line {}: {}: {}", line, pass, err);
+            }
+            println!("{:>4}  {:>8}  empty seats", "row", "occupied");
+            for row in &occupancy {
+                let columns: Vec<String> = empty
+                    .get(&row.row)
+                    .into_iter()
+                    .flatten()
+                    .map(|seat| seat.column().to_string())
+                    .collect();
+                let occupied = format!("{}/{}", row.occupied, row.seats);
+                println!("{:>4}  {:>8}  {}", row.row, occupied, columns.join(" "));
+            }
+            println!("{} ways to seat a party of {}", options.len(), party);
+            for option in options.iter().take(5) {
+                let passes: Vec<String> = option.iter().map(Seat::to_string).collect();
+                println!("  row {}: {}", option[0].row(), passes.join(" "));
             }
         }
-        Ok(Seat(val))
+        Format::Json => {
+            let rejected: Vec<_> = rejected
+                .iter()
+                .map(|(line, pass, err)| {
+                    serde_json::json!({ "line": line, "pass": pass, "error": err })
+                })
+                .collect();
+            let occupancy: Vec<_> = occupancy
+                .iter()
+                .map(|row| {
+                    let empty: Vec<u32> = empty
+                        .get(&row.row)
+                        .into_iter()
+                        .flatten()
+                        .map(Seat::column)
+                        .collect();
+                    serde_json::json!({
+                        "row": row.row,
+                        "occupied": row.occupied,
+                        "seats": row.seats,
+                        "empty": empty,
+                    })
+                })
+                .collect();
+            let options: Vec<Vec<String>> = options
+                .iter()
+                .map(|option| option.iter().map(Seat::to_string).collect())
+                .collect();
+            report.extra("rejected", rejected)?;
+            report.extra("rows", occupancy)?;
+            report.extra(
+                "party",
+                serde_json::json!({ "size": party, "options": options }),
+            )?;
+            report.print(Format::Json)?;
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn parse() {
-        let seat: Seat = "FBFBBFFRLR".parse().unwrap();
-        assert_eq!(seat.row(), 44);
-        assert_eq!(seat.column(), 5);
-        assert_eq!(seat.id(), 357);
-    }
+    Ok(())
 }
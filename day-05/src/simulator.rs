@@ -0,0 +1,172 @@
+//! Seating passengers one boarding pass at a time, for planes where some rows don't exist.
+
+use crate::{Layout, Seat};
+use std::collections::BTreeMap;
+
+/// A plane's shape, and how many rows at the front and back have no seats.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Plane {
+    pub layout: Layout,
+    pub blocked_front: u32,
+    pub blocked_back: u32,
+}
+
+impl Plane {
+    /// The rows that have seats.
+    pub fn rows(&self) -> std::ops::Range<u32> {
+        let end = self.layout.rows().saturating_sub(self.blocked_back);
+        self.blocked_front.min(end)..end
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Eq, PartialEq)]
+pub enum BoardingError {
+    #[error("Seat {0} already has a passenger")]
+    Duplicate(Seat),
+    #[error("Seat {0} is in row {row}, which has no seats", row = .0.row())]
+    BlockedRow(Seat),
+    #[error("Seat {0} is for a different plane")]
+    WrongPlane(Seat),
+}
+
+/// How full one row is.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RowOccupancy {
+    pub row: u32,
+    pub occupied: u32,
+    pub seats: u32,
+}
+
+pub struct Simulator {
+    plane: Plane,
+    occupied: Vec<bool>,
+}
+
+impl Simulator {
+    pub fn new(plane: Plane) -> Simulator {
+        let seats = plane.layout.rows() * plane.layout.columns();
+        Simulator {
+            plane,
+            occupied: vec![false; seats as usize],
+        }
+    }
+
+    /// Seat the passenger with a boarding pass, unless the seat is taken or doesn't exist.
+    pub fn board(&mut self, seat: Seat) -> Result<(), BoardingError> {
+        if seat.layout() != self.plane.layout {
+            return Err(BoardingError::WrongPlane(seat));
+        }
+        if !self.plane.rows().contains(&seat.row()) {
+            return Err(BoardingError::BlockedRow(seat));
+        }
+        let occupied = &mut self.occupied[seat.id() as usize];
+        if *occupied {
+            return Err(BoardingError::Duplicate(seat));
+        }
+        *occupied = true;
+        Ok(())
+    }
+
+    /// Every seat in the row, from left to right.
+    fn row(&self, row: u32) -> impl Iterator<Item = Seat> + '_ {
+        let layout = self.plane.layout;
+        (0..layout.columns()).filter_map(move |column| Seat::new(row, column, layout))
+    }
+
+    fn is_free(&self, seat: &Seat) -> bool {
+        !self.occupied[seat.id() as usize]
+    }
+
+    /// The empty seats in each row that has any, leaving out the rows that have no seats.
+    pub fn empty_seats(&self) -> BTreeMap<u32, Vec<Seat>> {
+        self.plane
+            .rows()
+            .map(|row| (row, self.row(row).filter(|s| self.is_free(s)).collect()))
+            .filter(|(_, seats): &(u32, Vec<Seat>)| !seats.is_empty())
+            .collect()
+    }
+
+    /// Every way to seat a party of `size` side by side in one row, without the aisle between
+    /// them, from the front of the plane.
+    pub fn seats_for_party(&self, size: usize) -> Vec<Vec<Seat>> {
+        if size == 0 {
+            return Vec::new();
+        }
+        let aisle = self.plane.layout.aisle();
+        let mut options = Vec::new();
+        for row in self.plane.rows() {
+            let seats: Vec<Seat> = self.row(row).collect();
+            let (left, right) = seats.split_at(aisle as usize);
+            for side in &[left, right] {
+                for window in side.windows(size) {
+                    if window.iter().all(|seat| self.is_free(seat)) {
+                        options.push(window.to_vec());
+                    }
+                }
+            }
+        }
+        options
+    }
+
+    /// How many seats are taken in each row that has seats.
+    pub fn occupancy(&self) -> Vec<RowOccupancy> {
+        self.plane
+            .rows()
+            .map(|row| RowOccupancy {
+                row,
+                occupied: self.row(row).filter(|s| !self.is_free(s)).count() as u32,
+                seats: self.plane.layout.columns(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seat(pass: &str, layout: Layout) -> Seat {
+        Seat::parse_with(pass, layout).unwrap()
+    }
+
+    #[test]
+    fn simulate() {
+        // Four rows of four, and the front row has no seats.
+        let layout = Layout::new(2, 2).unwrap();
+        let plane = Plane {
+            layout,
+            blocked_front: 1,
+            blocked_back: 0,
+        };
+        let mut simulator = Simulator::new(plane);
+
+        assert_eq!(simulator.board(seat("FBLR", layout)), Ok(()));
+        assert_eq!(simulator.board(seat("BFRL", layout)), Ok(()));
+        assert_eq!(
+            simulator.board(seat("FBLR", layout)),
+            Err(BoardingError::Duplicate(seat("FBLR", layout)))
+        );
+        assert_eq!(
+            simulator.board(seat("FFLL", layout)),
+            Err(BoardingError::BlockedRow(seat("FFLL", layout)))
+        );
+        assert!(simulator.board("FBFBBFFRLR".parse().unwrap()).is_err());
+
+        let empty = simulator.empty_seats();
+        assert_eq!(empty.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        let columns: Vec<u32> = empty[&1].iter().map(Seat::column).collect();
+        assert_eq!(columns, vec![0, 2, 3]);
+
+        // Row 1's left side has a passenger, and so does row 2's right side.
+        let parties: Vec<String> = simulator
+            .seats_for_party(2)
+            .iter()
+            .map(|party| party[0].to_string())
+            .collect();
+        assert_eq!(parties, vec!["FBRL", "BFLL", "BBLL", "BBRL"]);
+        assert!(simulator.seats_for_party(3).is_empty());
+
+        let occupancy: Vec<u32> = simulator.occupancy().iter().map(|r| r.occupied).collect();
+        assert_eq!(occupancy, vec![1, 1, 0]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Compares ways of adding up a large generated survey's answers: the original fold that builds a
+//! new `HashSet` for every form, `Survey`'s per-group frequency tables, and `LetterSurvey`'s
+//! bitmasks. Each approach parses the survey file and answers both parts.
+//!
+//! Run with `cargo bench -p day-06`.
+
+use aoc_core::bench::{print_header, time_runs, Lcg};
+use day_06::{Group, LetterSurvey, Letters, Survey};
+use std::collections::HashSet;
+
+const RUNS: usize = 5;
+
+fn main() {
+    print_header("groups", "approach");
+    for &len in &[1_000, 10_000, 100_000, 500_000] {
+        let path = std::env::temp_dir().join(format!("day-06-survey-{}.txt", len));
+        std::fs::write(&path, generate(len)).unwrap();
+        let input = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let original = time_runs(len, "original", RUNS, || original(&input));
+        let hash_sets = time_runs(len, "hash sets", RUNS, || {
+            let survey = Survey::chars(&input);
+            (
+                survey.sum(Group::total_yeses),
+                survey.sum(Group::everyone_yeses),
+            )
+        });
+        let bitmask = time_runs(len, "bitmask", RUNS, || {
+            let survey = LetterSurvey::parse(&input).unwrap();
+            (
+                survey.anyone().map(Letters::count).sum(),
+                survey.everyone().map(Letters::count).sum(),
+            )
+        });
+
+        assert_eq!(hash_sets, original);
+        assert_eq!(bitmask, original);
+    }
+}
+
+/// `len` groups of one to eight people, each answering up to a dozen letters. Letters early in
+/// the alphabet are more popular, so some groups agree on a few questions.
+fn generate(len: usize) -> String {
+    let mut lcg = Lcg::new(0x2020);
+    let mut next = |n: u64| lcg.below(n);
+
+    let mut input = String::new();
+    for group in 0..len {
+        if group > 0 {
+            input.push('\n');
+        }
+        for _ in 0..=next(8) {
+            for _ in 0..=next(12) {
+                let letter = next(26).min(next(26));
+                input.push(char::from(b'a' + letter as u8));
+            }
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// How both parts were originally solved: folding each group's forms into a new `HashSet` at
+/// every step, starting from `a` to `z` for the intersection.
+fn original(input: &str) -> (usize, usize) {
+    let groups: Vec<Vec<HashSet<char>>> = input
+        .split("\n\n")
+        .map(|block| {
+            block
+                .trim()
+                .split('\n')
+                .map(|form| form.chars().collect())
+                .collect()
+        })
+        .collect();
+
+    let anyone = groups
+        .iter()
+        .map(|forms| {
+            forms
+                .iter()
+                .fold(HashSet::new(), |all, form| {
+                    all.union(form).cloned().collect()
+                })
+                .len()
+        })
+        .sum();
+    let everyone = groups
+        .iter()
+        .map(|forms| {
+            let initial: HashSet<char> = ('a'..='z').collect();
+            forms
+                .iter()
+                .fold(initial, |all, form| {
+                    all.intersection(form).cloned().collect()
+                })
+                .len()
+        })
+        .sum();
+    (anyone, everyone)
+}
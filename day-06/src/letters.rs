@@ -0,0 +1,124 @@
+//! Forms answered only with the letters `a` to `z`, as in the puzzle, packed one bit per letter so
+//! that a group's answers combine with a single `|` or `&` per form.
+
+/// The letters a form answered yes to, one bit per letter from `a` to `z`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Letters(u32);
+
+impl Letters {
+    pub const NONE: Letters = Letters(0);
+    pub const ALL: Letters = Letters((1 << 26) - 1);
+
+    /// Pack a form's answers, ignoring whitespace, or `None` if any answer isn't `a` to `z`.
+    pub fn parse(form: &str) -> Option<Self> {
+        let mut bits = 0;
+        for byte in form.bytes() {
+            match byte {
+                b'a'..=b'z' => bits |= 1 << (byte - b'a'),
+                _ if byte.is_ascii_whitespace() => {}
+                _ => return None,
+            }
+        }
+        Some(Letters(bits))
+    }
+
+    pub fn union(self, other: Letters) -> Letters {
+        Letters(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Letters) -> Letters {
+        Letters(self.0 & other.0)
+    }
+
+    pub fn count(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn contains(self, letter: char) -> bool {
+        letter.is_ascii_lowercase() && self.0 & (1 << (letter as u8 - b'a')) != 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        (b'a'..=b'z')
+            .filter(move |letter| self.0 & (1 << (letter - b'a')) != 0)
+            .map(char::from)
+    }
+}
+
+/// A survey where every answer is a letter from `a` to `z`, split into groups and forms the same
+/// way as `Survey::chars`.
+#[derive(Clone, Debug)]
+pub struct LetterSurvey {
+    groups: Vec<Vec<Letters>>,
+}
+
+impl LetterSurvey {
+    /// `None` if any answer isn't `a` to `z`; use `Survey::chars` for those.
+    pub fn parse(input: &str) -> Option<Self> {
+        let groups = input
+            .split("\n\n")
+            .map(|block| block.trim().split('\n').map(Letters::parse).collect())
+            .collect::<Option<_>>()?;
+        Some(LetterSurvey { groups })
+    }
+
+    pub fn groups(&self) -> &[Vec<Letters>] {
+        &self.groups
+    }
+
+    /// The letters anyone in each group answered yes to.
+    pub fn anyone(&self) -> impl Iterator<Item = Letters> + '_ {
+        self.groups.iter().map(|forms| {
+            forms
+                .iter()
+                .fold(Letters::NONE, |all, &form| all.union(form))
+        })
+    }
+
+    /// The letters everyone in each group answered yes to.
+    pub fn everyone(&self) -> impl Iterator<Item = Letters> + '_ {
+        self.groups.iter().map(|forms| {
+            forms
+                .iter()
+                .fold(Letters::ALL, |all, &form| all.intersection(form))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Group, Survey};
+
+    #[test]
+    fn letters() {
+        let letters = Letters::parse("zab a\r").unwrap();
+        assert_eq!(letters.count(), 3);
+        assert_eq!(letters.iter().collect::<String>(), "abz");
+        assert!(letters.contains('z'));
+        assert!(!letters.contains('c'));
+        assert!(!letters.contains('A'));
+        assert_eq!(Letters::parse("aB"), None);
+        assert_eq!(Letters::parse("é"), None);
+    }
+
+    #[test]
+    fn same_as_hash_sets() {
+        let input = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb\n";
+        let letters = LetterSurvey::parse(input).unwrap();
+        let survey = Survey::chars(input);
+        let anyone: Vec<usize> = letters.anyone().map(Letters::count).collect();
+        let everyone: Vec<usize> = letters.everyone().map(Letters::count).collect();
+        let groups = survey.groups();
+        assert_eq!(
+            anyone,
+            groups.iter().map(Group::total_yeses).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            everyone,
+            groups.iter().map(Group::everyone_yeses).collect::<Vec<_>>()
+        );
+        assert_eq!(anyone, [3, 3, 3, 1, 1]);
+        assert_eq!(everyone, [3, 0, 1, 1, 1]);
+    }
+}
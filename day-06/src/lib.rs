@@ -0,0 +1,346 @@
+use aoc_core::{Answer, ParseError, Result, Solution};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+
+mod letters;
+
+pub use letters::{LetterSurvey, Letters};
+
+pub struct Day06 {
+    answers: Answers,
+}
+
+/// Packed letters when every answer is `a` to `z`, as in the puzzle, and sets of any characters
+/// otherwise.
+enum Answers {
+    Letters(LetterSurvey),
+    Any(Survey),
+}
+
+impl Solution<'_> for Day06 {
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let answers = match LetterSurvey::parse(input) {
+            Some(letters) => Answers::Letters(letters),
+            None => Answers::Any(Survey::chars(input)),
+        };
+        Ok(Day06 { answers })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let total = match &self.answers {
+            Answers::Letters(letters) => letters.anyone().map(Letters::count).sum(),
+            Answers::Any(survey) => survey.sum(Group::total_yeses),
+        };
+        Ok(total.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let total = match &self.answers {
+            Answers::Letters(letters) => letters.everyone().map(Letters::count).sum(),
+            Answers::Any(survey) => survey.sum(Group::everyone_yeses),
+        };
+        Ok(total.into())
+    }
+}
+
+/// Something a form can answer yes to: a letter, as in the puzzle, or anything else that can be
+/// compared and sorted, such as a word.
+pub trait Token: Clone + Eq + Hash + Ord {}
+
+impl<T: Clone + Eq + Hash + Ord> Token for T {}
+
+/// Every group's forms, separated by blank lines, with one person's form on each line.
+#[derive(Clone, Debug)]
+pub struct Survey<T = char> {
+    groups: Vec<Group<T>>,
+}
+
+impl Survey<char> {
+    /// Each character of a form is a question answered yes, as in the puzzle.
+    pub fn chars(input: &str) -> Self {
+        Survey::parse_with(input, |form| form.chars().filter(|c| !c.is_whitespace()))
+    }
+}
+
+impl Survey<String> {
+    /// Each whitespace-separated word of a form is a question answered yes.
+    pub fn words(input: &str) -> Self {
+        Survey::parse_with(input, |form| form.split_whitespace().map(str::to_string))
+    }
+}
+
+/// Surveys answer the puzzle too, more slowly than `Day06`, for reports that need every form.
+impl Solution<'_> for Survey<char> {
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Survey::chars(input))
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.sum(Group::total_yeses).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.sum(Group::everyone_yeses).into())
+    }
+}
+
+/// The puzzle's answers with each word as a question.
+impl Solution<'_> for Survey<String> {
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Survey::words(input))
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.sum(Group::total_yeses).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.sum(Group::everyone_yeses).into())
+    }
+}
+
+impl<T: Token> Survey<T> {
+    /// Split the input into groups and forms, and each form into tokens with `tokens`.
+    pub fn parse_with<'a, F, I>(input: &'a str, tokens: F) -> Self
+    where
+        F: Fn(&'a str) -> I,
+        I: IntoIterator<Item = T>,
+    {
+        let groups = input
+            .split("\n\n")
+            .map(|block| {
+                Group::new(
+                    block
+                        .trim()
+                        .split('\n')
+                        .map(|form| Form::new(tokens(form)))
+                        .collect(),
+                )
+            })
+            .collect();
+        Survey { groups }
+    }
+
+    pub fn groups(&self) -> &[Group<T>] {
+        &self.groups
+    }
+
+    /// Add up a count over every group, e.g. `survey.sum(|group| group.count_at_least(2))`.
+    pub fn sum(&self, count: impl Fn(&Group<T>) -> usize) -> usize {
+        self.groups.iter().map(count).sum()
+    }
+
+    /// Every question anyone in any group answered yes to.
+    pub fn questions(&self) -> BTreeSet<&T> {
+        self.groups
+            .iter()
+            .flat_map(|group| &group.forms)
+            .flat_map(|form| &form.answers)
+            .collect()
+    }
+
+    /// How each question was answered across every group.
+    pub fn frequencies(&self) -> BTreeMap<&T, Frequency> {
+        let mut table: BTreeMap<&T, Frequency> = self
+            .questions()
+            .into_iter()
+            .map(|question| (question, Frequency::default()))
+            .collect();
+        for group in &self.groups {
+            let members = group.members();
+            for (question, yeses) in group.frequencies() {
+                let frequency = table
+                    .get_mut(question)
+                    .expect("every question is in the table");
+                frequency.people += yeses;
+                frequency.anyone += 1;
+                if yeses == members {
+                    frequency.everyone += 1;
+                }
+            }
+        }
+        for frequency in table.values_mut() {
+            frequency.nobody = self.groups.len() - frequency.anyone;
+        }
+        table
+    }
+}
+
+/// How one question was answered across a survey.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize)]
+pub struct Frequency {
+    /// People who answered yes.
+    pub people: usize,
+    /// Groups where anyone answered yes.
+    pub anyone: usize,
+    /// Groups where everyone answered yes.
+    pub everyone: usize,
+    /// Groups where nobody answered yes.
+    pub nobody: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Group<T = char> {
+    forms: Vec<Form<T>>,
+}
+
+impl<T: Token> Group<T> {
+    pub fn new(forms: Vec<Form<T>>) -> Self {
+        Group { forms }
+    }
+
+    /// How many people are in the group.
+    pub fn members(&self) -> usize {
+        self.forms.len()
+    }
+
+    /// How many members answered yes to each question that anyone answered yes to.
+    pub fn frequencies(&self) -> HashMap<&T, usize> {
+        let mut frequencies = HashMap::new();
+        for answer in self.forms.iter().flat_map(|form| &form.answers) {
+            *frequencies.entry(answer).or_insert(0) += 1;
+        }
+        frequencies
+    }
+
+    /// How many questions at least `k` members answered yes to. `k` of 0 counts the questions
+    /// anyone answered, like `k` of 1.
+    pub fn count_at_least(&self, k: usize) -> usize {
+        self.frequencies().values().filter(|n| **n >= k).count()
+    }
+
+    /// How many questions exactly `k` members answered yes to. `k` of 0 counts nothing, since
+    /// the group can't know about questions nobody answered; see `unanswered`.
+    pub fn count_exactly(&self, k: usize) -> usize {
+        self.frequencies().values().filter(|n| **n == k).count()
+    }
+
+    /// Which of `questions` nobody in the group answered yes to.
+    pub fn unanswered<'a>(&self, questions: impl IntoIterator<Item = &'a T>) -> Vec<&'a T>
+    where
+        T: 'a,
+    {
+        let frequencies = self.frequencies();
+        questions
+            .into_iter()
+            .filter(|question| !frequencies.contains_key(question))
+            .collect()
+    }
+
+    /// How many questions anyone answered yes to.
+    pub fn total_yeses(&self) -> usize {
+        self.count_at_least(1)
+    }
+
+    /// How many questions everyone answered yes to.
+    pub fn everyone_yeses(&self) -> usize {
+        self.count_exactly(self.members())
+    }
+}
+
+impl std::str::FromStr for Group {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let forms = s
+            .trim()
+            .split('\n')
+            .map(|form| form.parse())
+            .collect::<Result<Vec<Form>, _>>()?;
+        Ok(Self { forms })
+    }
+}
+
+/// The questions one person answered yes to.
+#[derive(Clone, Debug)]
+pub struct Form<T = char> {
+    answers: HashSet<T>,
+}
+
+impl<T: Token> Form<T> {
+    pub fn new(answers: impl IntoIterator<Item = T>) -> Self {
+        Form {
+            answers: answers.into_iter().collect(),
+        }
+    }
+
+    pub fn answers(&self) -> &HashSet<T> {
+        &self.answers
+    }
+}
+
+impl std::str::FromStr for Form {
+    type Err = std::convert::Infallible;
+
+    /// Every character other than whitespace is a question answered yes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Form::new(s.chars().filter(|c| !c.is_whitespace())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb\n";
+
+    #[test]
+    fn example() {
+        let survey = Survey::chars(EXAMPLE);
+        assert_eq!(survey.sum(Group::total_yeses), 11);
+        assert_eq!(survey.sum(Group::everyone_yeses), 6);
+        assert_eq!(survey.sum(|group| group.count_at_least(2)), 2);
+        assert_eq!(survey.sum(|group| group.count_exactly(1)), 9);
+
+        let questions = survey.questions();
+        assert_eq!(
+            survey.sum(|group| group.unanswered(questions.clone()).len()),
+            4
+        );
+
+        let frequencies = survey.frequencies();
+        assert_eq!(
+            frequencies[&'a'],
+            Frequency {
+                people: 8,
+                anyone: 4,
+                everyone: 3,
+                nobody: 1
+            }
+        );
+    }
+
+    #[test]
+    fn any_tokens() {
+        // Capital letters used to be left out when everyone answered them.
+        let survey = Survey::chars("aB\nBé\n");
+        assert_eq!(survey.sum(Group::everyone_yeses), 1);
+        assert_eq!(survey.sum(Group::total_yeses), 3);
+
+        let survey = Survey::words("red green\ngreen blue\n\nred\n");
+        assert_eq!(survey.sum(Group::everyone_yeses), 2);
+        assert_eq!(survey.part2().unwrap(), 2.into());
+        let frequencies = survey.frequencies();
+        assert_eq!(frequencies[&"green".to_string()].nobody, 1);
+        assert_eq!(frequencies[&"red".to_string()].people, 2);
+    }
+
+    #[test]
+    fn falls_back_to_sets() {
+        let day = Day06::parse("aB\nBé\n").unwrap();
+        assert!(matches!(day.answers, Answers::Any(_)));
+        assert_eq!(day.part1().unwrap().to_string(), "3");
+        assert_eq!(day.part2().unwrap().to_string(), "1");
+
+        let day = Day06::parse("ab\nac\n").unwrap();
+        assert!(matches!(day.answers, Answers::Letters(_)));
+        assert_eq!(day.part1().unwrap().to_string(), "3");
+        assert_eq!(day.part2().unwrap().to_string(), "1");
+    }
+}
@@ -1,68 +1,94 @@
-use std::collections::HashSet;
-use std::io::Read;
+use aoc_core::cli::{Cli, Format};
+use aoc_core::{Report, Solution};
+use day_06::{Day06, Group, Survey, Token};
 
-fn main() {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input).unwrap();
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(&[("report", ""), ("words", ""), ("k", "K")]);
+    let k = cli.flag_or("k", 2)?;
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
 
-    let groups = input
-        .split("\n\n")
-        .map(|block| block.parse())
-        .collect::<Result<Vec<Group>, _>>()
-        .unwrap();
-
-    let r: usize = groups.iter().map(|group| group.total_yeses()).sum();
-    println!("{}", r);
-
-    let r: usize = groups.iter().map(|group| group.everyone_yeses()).sum();
-    println!("{}", r);
-}
-
-#[derive(Clone, Debug)]
-struct Group {
-    forms: Vec<Form>,
-}
-
-impl Group {
-    fn total_yeses(&self) -> usize {
-        let all_yeses = self.forms.iter().fold(HashSet::new(), |hashset, form| {
-            hashset.union(&form.answers).cloned().collect()
-        });
-        all_yeses.len()
+    // The report works on any kind of answers, so it doesn't go through the puzzle's parts.
+    if cli.switch("report") {
+        return if cli.switch("words") {
+            report(&input, Survey::words, k, &cli)
+        } else {
+            report(&input, Survey::chars, k, &cli)
+        };
     }
-
-    fn everyone_yeses(&self) -> usize {
-        let initial: HashSet<char> = ('a'..='z').collect();
-        let everyone_yeses = self.forms.iter().fold(initial, |hashset, form| {
-            hashset.intersection(&form.answers).cloned().collect()
-        });
-        everyone_yeses.len()
-    }
-}
-
-impl std::str::FromStr for Group {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let forms = s
-            .trim()
-            .split('\n')
-            .map(|form| form.parse())
-            .collect::<Result<Vec<Form>, _>>()?;
-        Ok(Self { forms })
+    if cli.switch("words") {
+        return Err("--words only applies to --report".into());
     }
-}
 
-#[derive(Clone, Debug)]
-struct Form {
-    answers: HashSet<char>,
+    let (_, report) = Report::solve::<Day06>(&input)?;
+    report.print(cli.format())
 }
 
-impl std::str::FromStr for Form {
-    type Err = &'static str;
+/// How the whole survey was answered: counts over every group, then a row per question.
+fn report<'a, T>(
+    input: &'a str,
+    parse: fn(&'a str) -> Survey<T>,
+    k: usize,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    T: Token + std::fmt::Display + serde::Serialize,
+    Survey<T>: Solution<'a>,
+{
+    let (survey, mut report) = Report::solve_parsed(input, |input| Ok(parse(input)))?;
+    let questions = survey.questions();
+    let aggregates = [
+        ("anyone", survey.sum(Group::total_yeses)),
+        ("everyone", survey.sum(Group::everyone_yeses)),
+        ("at least k", survey.sum(|group| group.count_at_least(k))),
+        ("exactly k", survey.sum(|group| group.count_exactly(k))),
+        (
+            "nobody",
+            survey.sum(|group| group.unanswered(questions.iter().copied()).len()),
+        ),
+    ];
+    let frequencies = survey.frequencies();
+    let people = survey.sum(Group::members);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let answers = s.trim().chars().collect();
-        Ok(Self { answers })
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            println!(
+                "{} groups, {} people, k = {}",
+                survey.groups().len(),
+                people,
+                k
+            );
+            for (name, count) in &aggregates {
+                println!("{:>10}: {}", name, count);
+            }
+            println!();
+            println!(
+                "{:<10} {:>7} {:>7} {:>8} {:>7}",
+                "question", "people", "anyone", "everyone", "nobody"
+            );
+            for (question, frequency) in &frequencies {
+                println!(
+                    "{:<10} {:>7} {:>7} {:>8} {:>7}",
+                    question.to_string(),
+                    frequency.people,
+                    frequency.anyone,
+                    frequency.everyone,
+                    frequency.nobody
+                );
+            }
+        }
+        Format::Json => {
+            let aggregates: serde_json::Map<String, serde_json::Value> = aggregates
+                .iter()
+                .map(|(name, count)| (name.replace(' ', "_"), (*count).into()))
+                .collect();
+            report.extra("groups", survey.groups().len())?;
+            report.extra("people", people)?;
+            report.extra("k", k)?;
+            report.extra("aggregates", aggregates)?;
+            report.extra("questions", frequencies)?;
+            report.print(Format::Json)?;
+        }
     }
+    Ok(())
 }
@@ -0,0 +1,155 @@
+//! Every rule at once, as a graph with a node for each color of bag and an edge from each bag to
+//! each bag it must directly contain, weighted by how many.
+
+use crate::rule::{BagRuleParseError, Rule};
+use aoc_core::ParseError;
+use std::collections::HashMap;
+
+/// A node of a `BagGraph`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BagId(pub(crate) usize);
+
+/// `count` bags of the color `bag`, inside another bag.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Edge {
+    pub bag: BagId,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BagGraph<'a> {
+    colors: Vec<&'a str>,
+    ids: HashMap<&'a str, BagId>,
+    contents: Vec<Vec<Edge>>,
+    ruled: Vec<bool>,
+}
+
+impl<'a> BagGraph<'a> {
+    /// Parse one rule per line. Blank lines are skipped, and errors have the line they're on.
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut graph = BagGraph::default();
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let rule = Rule::parse(line).map_err(|err| err.within(input, line))?;
+            if !graph.add(&rule) {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    BagRuleParseError::DuplicateRule,
+                ));
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Add a rule's edges, or return false if its color already has a rule.
+    pub fn add(&mut self, rule: &Rule<'a>) -> bool {
+        let bag = self.node(rule.color());
+        if self.ruled[bag.0] {
+            return false;
+        }
+        self.ruled[bag.0] = true;
+        for inner in rule.contents() {
+            let edge = Edge {
+                bag: self.node(inner.color),
+                count: inner.count,
+            };
+            self.contents[bag.0].push(edge);
+        }
+        true
+    }
+
+    fn node(&mut self, color: &'a str) -> BagId {
+        if let Some(&id) = self.ids.get(color) {
+            return id;
+        }
+        let id = BagId(self.colors.len());
+        self.colors.push(color);
+        self.ids.insert(color, id);
+        self.contents.push(Vec::new());
+        self.ruled.push(false);
+        id
+    }
+
+    /// How many colors there are, whether they have a rule or are only inside other bags.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    pub fn id(&self, color: &str) -> Option<BagId> {
+        self.ids.get(color).copied()
+    }
+
+    pub fn color(&self, bag: BagId) -> &'a str {
+        self.colors[bag.0]
+    }
+
+    /// Every bag, in the order its color first appears in the rules.
+    pub fn bags(&self) -> impl Iterator<Item = BagId> {
+        (0..self.colors.len()).map(BagId)
+    }
+
+    /// The bags that `bag` must directly contain. Empty for bags without a rule.
+    pub fn contents(&self, bag: BagId) -> &[Edge] {
+        &self.contents[bag.0]
+    }
+
+    /// Whether there's a rule for `bag`, rather than it only appearing inside other bags.
+    pub fn has_rule(&self, bag: BagId) -> bool {
+        self.ruled[bag.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph() {
+        let graph = BagGraph::parse(
+            "light red bags contain 1 bright white bag, 2 muted yellow bags.\n\
+             bright white bags contain 1 shiny gold bag.\n\
+             \n\
+             faded blue bags contain no other bags.\n",
+        )
+        .unwrap();
+        assert_eq!(graph.len(), 5);
+        let red = graph.id("light red").unwrap();
+        let yellow = graph.id("muted yellow").unwrap();
+        assert_eq!(
+            graph.contents(red)[1],
+            Edge {
+                bag: yellow,
+                count: 2
+            }
+        );
+        assert_eq!(graph.color(yellow), "muted yellow");
+        assert!(graph.has_rule(red));
+        assert!(!graph.has_rule(yellow));
+        assert!(graph.contents(yellow).is_empty());
+    }
+
+    #[test]
+    fn line_numbered_errors() {
+        let input = "faded blue bags contain no other bags.\n\
+                     light red bags contain 1 bright white bag 2 muted yellow bags.\n";
+        let err = BagGraph::parse(input).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 42));
+        assert_eq!(
+            err.kind::<BagRuleParseError>(),
+            Some(&BagRuleParseError::MissingSeparator)
+        );
+
+        let input = "faded blue bags contain no other bags.\n\
+                     faded blue bags contain 1 shiny gold bag.\n";
+        let err = BagGraph::parse(input).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(
+            err.kind::<BagRuleParseError>(),
+            Some(&BagRuleParseError::DuplicateRule)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use aoc_core::{Answer, ParseError, Result, Solution};
+
+mod graph;
+mod query;
+mod rule;
+
+pub use graph::{BagGraph, BagId, Edge};
+pub use query::BagQueryError;
+pub use rule::{BagRuleParseError, NumberedBag, Rule};
+
+const TARGET: &str = "shiny gold";
+
+pub struct Day07<'a> {
+    graph: BagGraph<'a>,
+}
+
+impl<'a> Solution<'a> for Day07<'a> {
+    const DAY: u8 = 7;
+
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Day07 {
+            graph: BagGraph::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let total = self
+            .graph
+            .id(TARGET)
+            .map_or(0, |bag| self.graph.ancestors(bag).len());
+        Ok(total.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let total = match self.graph.id(TARGET) {
+            Some(bag) => self.graph.contained(bag)?,
+            None => 0,
+        };
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,196 @@
+//! Questions about which bags end up inside which. Nothing here recurses, so long chains of bags
+//! can't overflow the stack, and rules where a bag ends up inside itself are reported rather than
+//! looping forever.
+
+use crate::{BagGraph, BagId};
+use std::collections::{BTreeSet, VecDeque};
+
+#[derive(Debug, thiserror::Error, Clone, Eq, PartialEq)]
+pub enum BagQueryError {
+    #[error("Bags end up inside themselves: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+    #[error("Too many bags inside {0} bags to count")]
+    Overflow(String),
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Mark {
+    New,
+    Open,
+    Done,
+}
+
+impl<'a> BagGraph<'a> {
+    /// Every bag that eventually contains `bag`, however deep inside it is.
+    pub fn ancestors(&self, bag: BagId) -> BTreeSet<BagId> {
+        let mut containers = vec![Vec::new(); self.len()];
+        for outer in self.bags() {
+            for edge in self.contents(outer) {
+                containers[edge.bag.0].push(outer);
+            }
+        }
+
+        let mut ancestors = BTreeSet::new();
+        let mut queue: VecDeque<BagId> = containers[bag.0].iter().copied().collect();
+        while let Some(outer) = queue.pop_front() {
+            if ancestors.insert(outer) {
+                queue.extend(&containers[outer.0]);
+            }
+        }
+        ancestors
+    }
+
+    /// How many bags `bag` must contain altogether, counting the bags inside bags. Each bag inside
+    /// it is only counted up once, however many times it's reached.
+    pub fn contained(&self, bag: BagId) -> Result<usize, BagQueryError> {
+        let mut totals = vec![0_usize; self.len()];
+        for outer in self.order(std::iter::once(bag))? {
+            let overflow = || BagQueryError::Overflow(self.color(outer).to_string());
+            totals[outer.0] = self.contents(outer).iter().try_fold(0_usize, |sum, edge| {
+                let each = totals[edge.bag.0].checked_add(1).ok_or_else(overflow)?;
+                edge.count
+                    .checked_mul(each)
+                    .and_then(|bags| sum.checked_add(bags))
+                    .ok_or_else(overflow)
+            })?;
+        }
+        Ok(totals[bag.0])
+    }
+
+    /// Every bag, with each bag after all the bags inside it, or the bags that end up inside
+    /// themselves if there's no such order.
+    pub fn topological_order(&self) -> Result<Vec<BagId>, BagQueryError> {
+        self.order(self.bags())
+    }
+
+    /// The bags reachable from `roots`, with each bag after all the bags inside it.
+    fn order(&self, roots: impl Iterator<Item = BagId>) -> Result<Vec<BagId>, BagQueryError> {
+        let mut marks = vec![Mark::New; self.len()];
+        let mut order = Vec::new();
+        for root in roots {
+            if marks[root.0] != Mark::New {
+                continue;
+            }
+            // The path from the root, with how many of each bag's contents have been visited.
+            marks[root.0] = Mark::Open;
+            let mut path = vec![(root, 0)];
+            while let Some((outer, next)) = path.last_mut() {
+                let outer = *outer;
+                let edge = match self.contents(outer).get(*next) {
+                    Some(edge) => edge,
+                    None => {
+                        marks[outer.0] = Mark::Done;
+                        order.push(outer);
+                        path.pop();
+                        continue;
+                    }
+                };
+                *next += 1;
+                match marks[edge.bag.0] {
+                    Mark::New => {
+                        marks[edge.bag.0] = Mark::Open;
+                        path.push((edge.bag, 0));
+                    }
+                    Mark::Open => {
+                        let start = path
+                            .iter()
+                            .position(|&(bag, _)| bag == edge.bag)
+                            .expect("open bags are on the path");
+                        let chain = path[start..]
+                            .iter()
+                            .map(|&(bag, _)| bag)
+                            .chain(std::iter::once(edge.bag))
+                            .map(|bag| self.color(bag).to_string())
+                            .collect();
+                        return Err(BagQueryError::Cycle(chain));
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    #[test]
+    fn example() {
+        let graph = BagGraph::parse(EXAMPLE).unwrap();
+        let gold = graph.id("shiny gold").unwrap();
+        let ancestors: BTreeSet<&str> = graph
+            .ancestors(gold)
+            .into_iter()
+            .map(|bag| graph.color(bag))
+            .collect();
+        assert_eq!(
+            ancestors,
+            ["bright white", "dark orange", "light red", "muted yellow"]
+                .iter()
+                .copied()
+                .collect()
+        );
+        assert_eq!(graph.contained(gold), Ok(32));
+
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order.len(), graph.len());
+        for outer in graph.bags() {
+            let position = |bag| order.iter().position(|&b| b == bag);
+            for edge in graph.contents(outer) {
+                assert!(position(edge.bag) < position(outer));
+            }
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let graph = BagGraph::parse(
+            "shiny gold bags contain 2 dark red bags.\n\
+             dark red bags contain 1 pale blue bag, 2 dark green bags.\n\
+             dark green bags contain 1 shiny gold bag.\n\
+             pale blue bags contain no other bags.\n",
+        )
+        .unwrap();
+        let gold = graph.id("shiny gold").unwrap();
+        assert_eq!(
+            graph.contained(gold),
+            Err(BagQueryError::Cycle(vec![
+                "shiny gold".to_string(),
+                "dark red".to_string(),
+                "dark green".to_string(),
+                "shiny gold".to_string(),
+            ]))
+        );
+        assert!(graph.topological_order().is_err());
+        // A bag in a cycle is inside itself.
+        assert_eq!(graph.ancestors(gold).len(), 3);
+        assert_eq!(graph.contained(graph.id("pale blue").unwrap()), Ok(0));
+    }
+
+    #[test]
+    fn deep() {
+        let rules: String = (0..100_000)
+            .map(|i| format!("color {} bags contain 2 color {} bags.\n", i, i + 1))
+            .collect();
+        let graph = BagGraph::parse(&rules).unwrap();
+        let last = graph.id("color 100000").unwrap();
+        assert_eq!(graph.ancestors(last).len(), 100_000);
+        let err = graph.contained(graph.id("color 0").unwrap()).unwrap_err();
+        assert_eq!(err, BagQueryError::Overflow("color 99936".to_string()));
+        assert_eq!(graph.contained(graph.id("color 99990").unwrap()), Ok(2046));
+    }
+}
@@ -0,0 +1,297 @@
+//! One line of the puzzle: which bags, and how many of each, a color of bag must contain.
+//!
+//! ```text
+//! rule     = color " bags contain " contents "."
+//! contents = "no other bags" | count " " color " bag" ["s"] { ", " count " " color " bag" ["s"] }
+//! color    = word { " " word }
+//! ```
+
+use aoc_core::{parse_number, ParseError};
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum BagRuleParseError {
+    #[error("Unexpected end of rule")]
+    UnexpectedEnd,
+    #[error("Expected a color")]
+    MissingColor,
+    #[error("Expected `{0}`")]
+    Expected(&'static str),
+    #[error("Expected `,` before the next bag or `.` at the end of the rule")]
+    MissingSeparator,
+    #[error("Expected `.` at the end of the rule")]
+    MissingPeriod,
+    #[error("Unexpected text after the end of the rule")]
+    TrailingText,
+    #[error("Expected a single space between words")]
+    ExtraSpace,
+    #[error("There's already a rule for this color")]
+    DuplicateRule,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rule<'a> {
+    color: &'a str,
+    contents: Vec<NumberedBag<'a>>,
+}
+
+/// `count` bags of `color`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NumberedBag<'a> {
+    pub count: usize,
+    pub color: &'a str,
+}
+
+impl<'a> Rule<'a> {
+    /// Parse a single rule, which may end with the `\r` of a CRLF line ending. Errors point
+    /// into the rule.
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let mut words = Words::new(line)?;
+
+        let (color, bags) = words.color()?;
+        if bags != "bags" {
+            return Err(ParseError::at(
+                line,
+                bags,
+                BagRuleParseError::Expected("bags"),
+            ));
+        }
+        words.expect("contain")?;
+
+        let mut contents = Vec::new();
+        if words.peek() == Some("no") {
+            words.expect("no")?;
+            words.expect("other")?;
+            let bags = words.next()?;
+            if bags.trim_end_matches('.') != "bags" {
+                return Err(ParseError::at(
+                    line,
+                    bags,
+                    BagRuleParseError::Expected("bags"),
+                ));
+            }
+            words.end(bags)?;
+            return Ok(Rule { color, contents });
+        }
+
+        loop {
+            let count = parse_number(line, words.next()?)?;
+            let (inner, bag) = words.color()?;
+            contents.push(NumberedBag {
+                count,
+                color: inner,
+            });
+            if !bag.ends_with(',') {
+                words.end(bag)?;
+                return Ok(Rule { color, contents });
+            }
+        }
+    }
+
+    /// The color of bag the rule is for.
+    pub fn color(&self) -> &'a str {
+        self.color
+    }
+
+    /// The bags it must directly contain, in the order the rule lists them.
+    pub fn contents(&self) -> &[NumberedBag<'a>] {
+        &self.contents
+    }
+}
+
+/// Words of a rule, each a slice of the rule so errors can point at them.
+struct Words<'a> {
+    line: &'a str,
+    words: std::iter::Peekable<std::str::Split<'a, char>>,
+}
+
+impl<'a> Words<'a> {
+    /// Words are separated by exactly one space, so an empty word is a space too many.
+    fn new(line: &'a str) -> Result<Self, ParseError> {
+        if let Some(empty) = line.split(' ').find(|word| word.is_empty()) {
+            return Err(ParseError::at(line, empty, BagRuleParseError::ExtraSpace));
+        }
+        Ok(Words {
+            line,
+            words: line.split(' ').peekable(),
+        })
+    }
+
+    fn peek(&mut self) -> Option<&'a str> {
+        self.words.peek().copied()
+    }
+
+    fn next(&mut self) -> Result<&'a str, ParseError> {
+        self.words
+            .next()
+            .ok_or_else(|| ParseError::at_end(self.line, BagRuleParseError::UnexpectedEnd))
+    }
+
+    fn expect(&mut self, expected: &'static str) -> Result<(), ParseError> {
+        let word = self.next()?;
+        if word == expected {
+            Ok(())
+        } else {
+            Err(ParseError::at(
+                self.line,
+                word,
+                BagRuleParseError::Expected(expected),
+            ))
+        }
+    }
+
+    /// A color of any number of words, up to the `bag` or `bags` after it, which is returned
+    /// with any punctuation after it.
+    fn color(&mut self) -> Result<(&'a str, &'a str), ParseError> {
+        let first = self.next()?;
+        if is_bag(first) {
+            return Err(ParseError::at(
+                self.line,
+                first,
+                BagRuleParseError::MissingColor,
+            ));
+        }
+        let mut last = first;
+        loop {
+            let word = self.next()?;
+            if is_bag(word) {
+                let start = offset(self.line, first);
+                let end = offset(self.line, last) + last.len();
+                return Ok((&self.line[start..end], word));
+            }
+            last = word;
+        }
+    }
+
+    /// Check that `last`, the last word of the rule, ends it with a period.
+    fn end(&mut self, last: &'a str) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(next) if last.ends_with('.') => Err(ParseError::at(
+                self.line,
+                next,
+                BagRuleParseError::TrailingText,
+            )),
+            Some(_) => Err(ParseError::at_offset(
+                self.line,
+                offset(self.line, last) + last.len(),
+                BagRuleParseError::MissingSeparator,
+            )),
+            None if !last.ends_with('.') => Err(ParseError::at_end(
+                self.line,
+                BagRuleParseError::MissingPeriod,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+fn is_bag(word: &str) -> bool {
+    matches!(word.trim_end_matches(&[',', '.'][..]), "bag" | "bags")
+}
+
+fn offset(line: &str, word: &str) -> usize {
+    word.as_ptr() as usize - line.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_1() {
+        let input =
+            "light red bags contain 1 bright white bag, 2 muted yellow bags, 3 bright orange bags.";
+        let input = Rule::parse(input).unwrap();
+        assert_eq!(input.color(), "light red");
+        assert_eq!(input.contents().len(), 3);
+        assert_eq!(input.contents()[0].count, 1);
+        assert_eq!(input.contents()[0].color, "bright white");
+    }
+
+    #[test]
+    fn input_2() {
+        let input = "faded blue bags contain no other bags.";
+        let input = Rule::parse(input).unwrap();
+        assert_eq!(input.color(), "faded blue");
+        assert_eq!(input.contents().len(), 0);
+    }
+
+    #[test]
+    fn input_invalid() {
+        let err = Rule::parse("faded blue bags contain x dotted black bags.").unwrap_err();
+        assert_eq!(err.column(), 25);
+
+        let err = Rule::parse("faded blue bags contain 1 dotted").unwrap_err();
+        assert_eq!(err.column(), 33);
+        assert_eq!(
+            err.kind::<BagRuleParseError>(),
+            Some(&BagRuleParseError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn colors_of_any_length() {
+        let rule = Rule::parse("red bags contain 1 very dark olive green bag, 12 x bags.").unwrap();
+        assert_eq!(rule.color(), "red");
+        assert_eq!(
+            rule.contents(),
+            [
+                NumberedBag {
+                    count: 1,
+                    color: "very dark olive green"
+                },
+                NumberedBag {
+                    count: 12,
+                    color: "x"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn punctuation() {
+        let kind = |rule: &str| {
+            let err = Rule::parse(rule).unwrap_err();
+            (*err.kind::<BagRuleParseError>().unwrap(), err.column())
+        };
+        assert_eq!(
+            kind("faded blue bags contain no other bags"),
+            (BagRuleParseError::MissingPeriod, 38)
+        );
+        assert_eq!(
+            kind("faded blue bags contain 2 red bags"),
+            (BagRuleParseError::MissingPeriod, 35)
+        );
+        assert_eq!(
+            kind("faded blue bags contain 2 red bags. 1 tan bag."),
+            (BagRuleParseError::TrailingText, 37)
+        );
+        assert_eq!(
+            kind("bags contain 2 red bags."),
+            (BagRuleParseError::MissingColor, 1)
+        );
+        assert_eq!(
+            kind("faded blue bag contain 2 red bags."),
+            (BagRuleParseError::Expected("bags"), 12)
+        );
+        assert_eq!(
+            kind("faded blue bags holds 2 red bags."),
+            (BagRuleParseError::Expected("contain"), 17)
+        );
+        assert_eq!(
+            kind("faded  blue bags contain 2 red bags."),
+            (BagRuleParseError::ExtraSpace, 7)
+        );
+        assert_eq!(
+            kind("faded blue bags contain 2 red bags. "),
+            (BagRuleParseError::ExtraSpace, 37)
+        );
+        assert_eq!(
+            kind(" faded blue bags contain 2 red bags."),
+            (BagRuleParseError::ExtraSpace, 1)
+        );
+
+        let rule = Rule::parse("faded blue bags contain 2 red bags.\r").unwrap();
+        assert_eq!(rule.contents()[0].color, "red");
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day08 {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution<'_> for Day08 {
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let instructions = input
+            .trim()
+            .lines()
+            .map(|s| s.parse::<Instruction>().map_err(|err| err.within(input, s)))
+            .collect::<Result<Vec<Instruction>, _>>()?;
+        Ok(Day08 { instructions })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut interpreter = Interpreter::new(&self.instructions);
+        let mut instruction_seen = HashSet::new();
+        loop {
+            if !instruction_seen.insert(interpreter.instruction_pointer) {
+                break;
+            }
+
+            interpreter.step()?;
+        }
+        Ok(interpreter.accumulator.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        for i in 0..self.instructions.len() {
+            let mut instructions = self.instructions.to_vec();
+            if !instructions[i].fix() {
+                continue;
+            }
+
+            let mut interpreter = Interpreter::new(&instructions);
+            let mut instruction_seen = HashSet::new();
+            let success = loop {
+                if !instruction_seen.insert(interpreter.instruction_pointer) {
+                    break false;
+                }
+
+                if interpreter.step()? == StepResult::Done {
+                    break true;
+                }
+            };
+
+            if success {
+                return Ok(interpreter.accumulator.into());
+            }
+        }
+
+        Err("No single fix makes the program terminate".into())
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Instruction {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+impl Instruction {
+    fn fix(&mut self) -> bool {
+        match self {
+            Instruction::Acc(_) => false,
+            Instruction::Nop(n) => {
+                *self = Instruction::Jmp(*n);
+                true
+            }
+            Instruction::Jmp(n) => {
+                *self = Instruction::Nop(*n);
+                true
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let space = s
+            .find(' ')
+            .ok_or_else(|| ParseError::at_end(s, InstructionParseError::MissingArgument))?;
+        let inst = &s[..space];
+        let n = parse_number(s, &s[(space + 1)..])?;
+        match inst {
+            "nop" => Ok(Instruction::Nop(n)),
+            "jmp" => Ok(Instruction::Jmp(n)),
+            "acc" => Ok(Instruction::Acc(n)),
+            _ => Err(ParseError::at(
+                s,
+                inst,
+                InstructionParseError::UnknownOperation(inst.to_string()),
+            )),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum InstructionParseError {
+    #[error("Unknown operation: {0}")]
+    UnknownOperation(String),
+    #[error("Missing argument")]
+    MissingArgument,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum InterpreterError {
+    #[error("Instruction pointer out of range")]
+    InstructionPointerOutOfRange,
+}
+
+struct Interpreter<'a> {
+    instruction_pointer: i64,
+    accumulator: i64,
+    instructions: &'a [Instruction],
+}
+
+impl<'a> Interpreter<'a> {
+    fn new(instructions: &'a [Instruction]) -> Self {
+        Self {
+            instruction_pointer: 0,
+            accumulator: 0,
+            instructions,
+        }
+    }
+
+    fn step(&mut self) -> Result<StepResult, InterpreterError> {
+        let instruction = self.fetch_instruction()?;
+        self.interpret_instruction(instruction)?;
+        if self.instruction_pointer >= 0
+            && self.instruction_pointer as usize == self.instructions.len()
+        {
+            Ok(StepResult::Done)
+        } else {
+            Ok(StepResult::Running)
+        }
+    }
+
+    fn fetch_instruction(&self) -> Result<Instruction, InterpreterError> {
+        if self.instruction_pointer < 0 {
+            return Err(InterpreterError::InstructionPointerOutOfRange);
+        }
+        let instruction_pointer = self.instruction_pointer as usize;
+        if let Some(instruction) = self.instructions.get(instruction_pointer) {
+            Ok(*instruction)
+        } else {
+            Err(InterpreterError::InstructionPointerOutOfRange)
+        }
+    }
+
+    fn interpret_instruction(&mut self, instruction: Instruction) -> Result<(), InterpreterError> {
+        match instruction {
+            Instruction::Nop(_) => {
+                self.instruction_pointer += 1;
+            }
+            Instruction::Acc(acc) => {
+                self.instruction_pointer += 1;
+                self.accumulator += acc;
+            }
+            Instruction::Jmp(off) => {
+                self.instruction_pointer += off;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum StepResult {
+    Done,
+    Running,
+}
@@ -0,0 +1,123 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+pub const DEFAULT_PRELUDE_SIZE: usize = 25;
+
+pub struct Day09 {
+    numbers: Vec<i64>,
+    prelude_size: usize,
+}
+
+impl Day09 {
+    /// Use a prelude other than the puzzle's 25 numbers, e.g. 5 for the example.
+    pub fn with_prelude_size(self, prelude_size: usize) -> Self {
+        Day09 {
+            prelude_size,
+            ..self
+        }
+    }
+
+    fn first_invalid(&self) -> Result<i64> {
+        let number =
+            first_invalid(&self.numbers, self.prelude_size).ok_or("Every number is valid")?;
+        Ok(number)
+    }
+}
+
+impl Solution<'_> for Day09 {
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let numbers = input
+            .trim()
+            .split('\n')
+            .map(|line| parse_number(input, line.trim()))
+            .collect::<Result<Vec<i64>, _>>()?;
+        Ok(Day09 {
+            numbers,
+            prelude_size: DEFAULT_PRELUDE_SIZE,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.first_invalid()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let number = self.first_invalid()?;
+        let weakness = find_weakness(number, &self.numbers).ok_or("No weakness found")?;
+        Ok(weakness.into())
+    }
+}
+
+pub fn first_invalid(numbers: &[i64], prelude_size: usize) -> Option<i64> {
+    let mut buffer = VecDeque::new();
+    for number in numbers {
+        let number = *number;
+        if buffer.len() < prelude_size {
+            buffer.push_back(number);
+            continue;
+        }
+
+        let valid = is_valid(number, &buffer);
+        if !valid {
+            return Some(number);
+        } else {
+            buffer.pop_front();
+            buffer.push_back(number);
+        }
+    }
+
+    None
+}
+
+fn is_valid(n: i64, deque: &VecDeque<i64>) -> bool {
+    let mut set = HashSet::new();
+    for m in deque {
+        if set.contains(&(n - m)) {
+            return true;
+        }
+        set.insert(m);
+    }
+
+    false
+}
+
+pub fn find_weakness(n: i64, numbers: &[i64]) -> Option<i64> {
+    let len = numbers.len();
+    let mut start_index = 0;
+    let mut end_index = 0;
+    let mut sum = numbers[0];
+
+    loop {
+        if sum == n {
+            let mut smallest = i64::MAX;
+            let mut largest = i64::MIN;
+            for number in &numbers[start_index..=end_index] {
+                if *number < smallest {
+                    smallest = *number;
+                }
+                if *number > largest {
+                    largest = *number;
+                }
+            }
+            return Some(smallest + largest);
+        }
+        if sum < n {
+            end_index += 1;
+            if end_index >= len {
+                break;
+            }
+            sum += numbers[end_index];
+        } else if sum > n {
+            sum -= numbers[start_index];
+            start_index += 1;
+            if start_index > end_index {
+                break;
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,144 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+
+#[derive(Debug)]
+pub struct Day10 {
+    chain: Chain,
+}
+
+impl Solution<'_> for Day10 {
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut adapters = input
+            .trim()
+            .split('\n')
+            .map(|line| Ok((parse_number(input, line)?, line)))
+            .collect::<Result<Vec<(usize, &str)>, ParseError>>()?;
+        adapters.sort_by_key(|&(joltage, _)| joltage);
+        let joltages: Vec<usize> = adapters.iter().map(|&(joltage, _)| joltage).collect();
+        let chain = Chain::build(&joltages)
+            .map_err(|err| ParseError::at(input, adapters[err.index()].1, err))?;
+        Ok(Day10 { chain })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok((self.chain.count_ones * self.chain.count_threes).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let ways = self
+            .chain
+            .ways
+            .ok_or("Too many ways to arrange the adapters to count")?;
+        Ok(ways.into())
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum ChainError {
+    #[error("The outlet or another adapter already has this joltage")]
+    Duplicate { index: usize },
+    #[error(
+        "This adapter is {jump} jolts higher than the next one down, but adapters take at most 3"
+    )]
+    Gap { index: usize, jump: usize },
+}
+
+impl ChainError {
+    /// Where the adapter that can't be chained is in the sorted joltages.
+    pub fn index(&self) -> usize {
+        match *self {
+            ChainError::Duplicate { index } | ChainError::Gap { index, .. } => index,
+        }
+    }
+}
+
+/// The result of chaining every adapter together, from the outlet to the device.
+#[derive(Debug, Clone, Copy)]
+pub struct Chain {
+    pub count_ones: usize,
+    pub count_threes: usize,
+    /// How many ways there are to get from the outlet to the device, or `None` if there are too
+    /// many to count.
+    pub ways: Option<usize>,
+}
+
+impl Chain {
+    /// Build the chain from a sorted list of adapter joltages, or say which adapter can't be part
+    /// of it.
+    pub fn build(joltages: &[usize]) -> Result<Chain, ChainError> {
+        let mut count_ones = 0;
+        // The device is always 3 jolts higher than the last adapter.
+        let mut count_threes = 1;
+        // The outlet and each adapter so far, with how many ways there are to reach it.
+        let mut reached: Vec<(usize, Option<usize>)> = vec![(0, Some(1))];
+        for (index, &joltage) in joltages.iter().enumerate() {
+            let (last_joltage, _) = reached[reached.len() - 1];
+            match joltage - last_joltage {
+                0 => return Err(ChainError::Duplicate { index }),
+                1 => count_ones += 1,
+                2 => {}
+                3 => count_threes += 1,
+                jump => return Err(ChainError::Gap { index, jump }),
+            }
+
+            // An adapter can be plugged into anything up to 3 jolts lower, and since no two
+            // joltages are the same, that's at most the last three.
+            let ways = reached
+                .iter()
+                .rev()
+                .take(3)
+                .filter(|&&(lower, _)| joltage - lower <= 3)
+                .try_fold(0_usize, |sum, &(_, ways)| sum.checked_add(ways?));
+            reached.push((joltage, ways));
+        }
+
+        // The device can only be plugged into the last adapter.
+        let (_, ways) = reached[reached.len() - 1];
+        Ok(Chain {
+            count_ones,
+            count_threes,
+            ways,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let day = Day10::parse("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n").unwrap();
+        assert_eq!((day.chain.count_ones, day.chain.count_threes), (7, 5));
+        assert_eq!(day.chain.ways, Some(8));
+    }
+
+    #[test]
+    fn jumps_of_two() {
+        let chain = Chain::build(&[2, 4, 5, 7]).unwrap();
+        assert_eq!((chain.count_ones, chain.count_threes), (1, 1));
+        // 0-2-4-5-7, 0-2-4-7, 0-2-5-7
+        assert_eq!(chain.ways, Some(3));
+    }
+
+    #[test]
+    fn unchainable() {
+        let err = Day10::parse("5\n100\n").unwrap_err();
+        assert_eq!(err.line(), 1);
+        assert_eq!(
+            err.kind::<ChainError>(),
+            Some(&ChainError::Gap { index: 0, jump: 5 })
+        );
+
+        let err = Day10::parse("1\n3\n2\n3\n").unwrap_err();
+        assert_eq!(err.line(), 4);
+        assert_eq!(
+            err.kind::<ChainError>(),
+            Some(&ChainError::Duplicate { index: 3 })
+        );
+
+        let chain = Chain::build(&(1..200).collect::<Vec<_>>()).unwrap();
+        assert_eq!(chain.ways, None);
+    }
+}
@@ -0,0 +1,288 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Notes {
+    pub earliest_departure: usize,
+    pub bus_ids: Vec<BusId>,
+}
+
+#[derive(Debug)]
+pub struct Day13 {
+    notes: Notes,
+}
+
+impl Solution<'_> for Day13 {
+    const DAY: u8 = 13;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.trim().split('\n');
+        let line1 = lines.next().unwrap_or_default().trim();
+        let line2 = lines
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, NotesParseError::MissingBusIds))?
+            .trim();
+        let earliest_departure = parse_number(input, line1)?;
+        let bus_ids = line2
+            .split(',')
+            .map(|id| BusId::parse(input, id))
+            .collect::<Result<_, _>>()?;
+        Ok(Day13 {
+            notes: Notes {
+                earliest_departure,
+                bus_ids,
+            },
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let (bus_id, wait_time) = earliest_bus(&self.notes).ok_or("No known buses")?;
+        Ok((bus_id * wait_time).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(find_solution(0, &self.notes.bus_ids).into())
+    }
+}
+
+/// The ID of the first bus to leave after the earliest departure, and how long it is to wait for
+/// it.
+pub fn earliest_bus(notes: &Notes) -> Option<(usize, usize)> {
+    let mut earliest_departure_time = None;
+    let mut earliest_departure_id = None;
+    for id in &notes.bus_ids {
+        if let Some(wait_time) = id.wait_time(notes.earliest_departure) {
+            if let Some(current_best) = earliest_departure_time {
+                if wait_time < current_best {
+                    earliest_departure_time = Some(wait_time);
+                    earliest_departure_id = Some(*id);
+                }
+            } else {
+                earliest_departure_time = Some(wait_time);
+                earliest_departure_id = Some(*id);
+            }
+        }
+    }
+
+    match (earliest_departure_id, earliest_departure_time) {
+        (Some(BusId::Known(id)), Some(wait_time)) => Some((id, wait_time)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum NotesParseError {
+    #[error("Missing bus IDs")]
+    MissingBusIds,
+    #[error("Bus IDs start at 1")]
+    ZeroBusId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum BusId {
+    Known(usize),
+    Unknown,
+}
+
+impl BusId {
+    /// Parse `s`, a slice of `input`.
+    pub fn parse(input: &str, s: &str) -> Result<BusId, ParseError> {
+        if s == "x" {
+            return Ok(BusId::Unknown);
+        }
+        match parse_number(input, s)? {
+            0 => Err(ParseError::at(input, s, NotesParseError::ZeroBusId)),
+            id => Ok(BusId::Known(id)),
+        }
+    }
+
+    fn wait_time(&self, earliest_departure: usize) -> Option<usize> {
+        match self {
+            BusId::Known(id) => Some(id - earliest_departure % id),
+            BusId::Unknown => None,
+        }
+    }
+}
+
+fn check_solution(t: usize, offsets: &[BusOffset]) -> bool {
+    offsets.iter().all(|bus| bus.valid(t))
+}
+
+pub fn find_solution(start_t: usize, buses: &[BusId]) -> usize {
+    let offsets: Vec<BusOffset> = buses
+        .iter()
+        .zip(0..)
+        .filter_map(|(bus_id, offset)| match bus_id {
+            BusId::Known(id) => Some(BusOffset { offset, id: *id }),
+            BusId::Unknown => None,
+        })
+        .collect();
+
+    let mut t = start_t;
+    loop {
+        let mut next_offset = 1;
+        if check_solution(t, &offsets) {
+            return t;
+        }
+
+        for offset in &offsets {
+            if offset.valid(t) {
+                next_offset *= offset.id;
+            }
+        }
+
+        t += next_offset;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BusOffset {
+    offset: usize,
+    id: usize,
+}
+
+impl From<(usize, usize)> for BusOffset {
+    fn from(v: (usize, usize)) -> Self {
+        Self {
+            offset: v.0,
+            id: v.1,
+        }
+    }
+}
+
+impl BusOffset {
+    fn valid(&self, t: usize) -> bool {
+        (t + self.offset).is_multiple_of(self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_bus_id() {
+        let err = Day13::parse("939\n7,x,0\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 5));
+        assert_eq!(
+            err.kind::<NotesParseError>(),
+            Some(&NotesParseError::ZeroBusId)
+        );
+    }
+
+    #[test]
+    fn check_offset_1() {
+        let offsets: Vec<BusOffset> = vec![
+            (0, 7).into(),
+            (1, 13).into(),
+            (4, 59).into(),
+            (6, 31).into(),
+            (7, 19).into(),
+        ];
+        assert!(check_solution(1068781, &offsets))
+    }
+
+    #[test]
+    fn check_offset_2() {
+        let offsets: Vec<BusOffset> = vec![(0, 17).into(), (2, 13).into(), (3, 19).into()];
+        assert!(check_solution(3417, &offsets))
+    }
+
+    #[test]
+    fn check_offset_3() {
+        let offsets: Vec<BusOffset> = vec![
+            (0, 67).into(),
+            (1, 7).into(),
+            (2, 59).into(),
+            (3, 61).into(),
+        ];
+        assert!(check_solution(754018, &offsets))
+    }
+
+    #[test]
+    fn find_offset_1() {
+        let buses = vec![
+            BusId::Known(7),
+            BusId::Known(13),
+            BusId::Unknown,
+            BusId::Unknown,
+            BusId::Known(59),
+            BusId::Unknown,
+            BusId::Known(31),
+            BusId::Known(19),
+        ];
+        assert_eq!(find_solution(0, &buses), 1068781);
+    }
+
+    #[test]
+    fn find_offset_1_with_start() {
+        let buses = vec![
+            BusId::Known(7),
+            BusId::Known(13),
+            BusId::Unknown,
+            BusId::Unknown,
+            BusId::Known(59),
+            BusId::Unknown,
+            BusId::Known(31),
+            BusId::Known(19),
+        ];
+        assert_eq!(find_solution(1060000, &buses), 1068781);
+        assert_eq!(find_solution(1060001, &buses), 1068781);
+    }
+
+    #[test]
+    fn find_offset_2() {
+        let buses = vec![
+            BusId::Known(17),
+            BusId::Unknown,
+            BusId::Known(13),
+            BusId::Known(19),
+        ];
+        assert_eq!(find_solution(0, &buses), 3417);
+    }
+
+    #[test]
+    fn find_offset_3() {
+        let buses = vec![
+            BusId::Known(67),
+            BusId::Known(7),
+            BusId::Known(59),
+            BusId::Known(61),
+        ];
+        assert_eq!(find_solution(0, &buses), 754018);
+    }
+
+    #[test]
+    fn find_offset_4() {
+        let buses = vec![
+            BusId::Known(67),
+            BusId::Unknown,
+            BusId::Known(7),
+            BusId::Known(59),
+            BusId::Known(61),
+        ];
+        assert_eq!(find_solution(0, &buses), 779210);
+    }
+
+    #[test]
+    fn find_offset_5() {
+        let buses = vec![
+            BusId::Known(67),
+            BusId::Known(7),
+            BusId::Unknown,
+            BusId::Known(59),
+            BusId::Known(61),
+        ];
+        assert_eq!(find_solution(0, &buses), 1261476);
+    }
+
+    #[test]
+    fn find_offset_6() {
+        let buses = vec![
+            BusId::Known(1789),
+            BusId::Known(37),
+            BusId::Known(47),
+            BusId::Known(1889),
+        ];
+        assert_eq!(find_solution(0, &buses), 1202161486);
+    }
+}
@@ -0,0 +1,256 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashMap;
+
+pub struct Day14 {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution<'_> for Day14 {
+    const DAY: u8 = 14;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let instructions = input
+            .trim()
+            .split('\n')
+            .map(|line| {
+                line.parse::<Instruction>()
+                    .map_err(|err| err.within(input, line))
+            })
+            .collect::<Result<Vec<Instruction>, _>>()?;
+        Ok(Day14 { instructions })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut program = Program::default();
+        for instruction in &self.instructions {
+            program.apply(*instruction);
+        }
+        Ok(program.address_space.values().sum::<u64>().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut program = Program::default();
+        for instruction in &self.instructions {
+            program.apply_part_2(*instruction);
+        }
+        Ok(program.address_space.values().sum::<u64>().into())
+    }
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct Mask {
+    and_mask: u64,
+    or_mask: u64,
+    floaters: u64,
+}
+
+impl std::str::FromStr for Mask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((i, ch)) = s
+            .char_indices()
+            .find(|(_, ch)| !matches!(ch, 'X' | '1' | '0'))
+        {
+            return Err(ParseError::at(
+                s,
+                &s[i..],
+                InstructionParseError::InvalidMaskCharacter(ch),
+            ));
+        }
+        if s.len() != 36 {
+            return Err(ParseError::at(
+                s,
+                &s[s.len().min(36)..],
+                InstructionParseError::InvalidMaskLength(s.len()),
+            ));
+        }
+
+        let mut offset = 36;
+        let mut or_mask = 0;
+        let mut and_mask = (1 << (offset + 1)) - 1;
+        let mut floaters = 0;
+        for ch in s.chars() {
+            offset -= 1;
+            match ch {
+                'X' => {
+                    floaters |= 1 << offset;
+                }
+                '1' => {
+                    or_mask |= 1 << offset;
+                }
+                _ => {
+                    and_mask &= !(1 << offset);
+                }
+            }
+        }
+        Ok(Mask {
+            and_mask,
+            or_mask,
+            floaters,
+        })
+    }
+}
+
+impl std::fmt::Debug for Mask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mask")
+            .field("and_mask", &format!("{:036b}", self.and_mask))
+            .field("or_mask", &format!("{:036b}", self.or_mask))
+            .finish()
+    }
+}
+
+impl Mask {
+    fn apply(&self, value: u64) -> u64 {
+        (value & self.and_mask) | self.or_mask
+    }
+
+    fn apply_address(&self, address: u64) -> Vec<u64> {
+        let base_address = (address | self.or_mask) & !self.floaters;
+        let ones = self.floaters.count_ones();
+        let num_options = 1 << ones;
+
+        let mut floaters: Vec<u64> = Vec::with_capacity(num_options);
+        for option in 0..num_options {
+            let value = self.floater_option_to_value(ones, option as u64);
+            floaters.push(value | base_address);
+        }
+
+        floaters
+    }
+
+    fn floater_option_to_value(&self, ones: u32, option: u64) -> u64 {
+        let mut local_floaters = self.floaters;
+        let mut result = 0;
+        for i in 0..ones {
+            let option_value = (option >> i) & 0x1;
+
+            for j in 0..36 {
+                if (local_floaters & (1 << j)) > 0 {
+                    // j is the index of a 1!
+                    result |= option_value << j;
+                    local_floaters &= !(1 << j);
+                    break;
+                }
+            }
+        }
+        result
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Instruction {
+    Mask(Mask),
+    Mem(usize, u64),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(mask) = s.strip_prefix("mask = ") {
+            let mask = mask.parse::<Mask>().map_err(|err| err.within(s, mask))?;
+            Ok(Instruction::Mask(mask))
+        } else if let Some(rest) = s.strip_prefix("mem[") {
+            let closing_bracket = rest
+                .find(']')
+                .ok_or_else(|| ParseError::at_end(s, InstructionParseError::MissingBracket))?;
+            let address = parse_number(s, &rest[..closing_bracket])?;
+            let rest = &rest[(closing_bracket + 1)..];
+            let value = rest
+                .strip_prefix(" = ")
+                .ok_or_else(|| ParseError::at(s, rest, InstructionParseError::MissingEquals))?;
+            let value = parse_number(s, value)?;
+            Ok(Instruction::Mem(address, value))
+        } else {
+            Err(ParseError::at(s, s, InstructionParseError::UnknownCommand))
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum InstructionParseError {
+    #[error("Unknown command")]
+    UnknownCommand,
+    #[error("Missing closing bracket")]
+    MissingBracket,
+    #[error("Missing equals")]
+    MissingEquals,
+    #[error("Invalid character in mask: {0}")]
+    InvalidMaskCharacter(char),
+    #[error("Mask is {0} characters long, not 36")]
+    InvalidMaskLength(usize),
+}
+
+#[derive(Default, Debug)]
+struct Program {
+    mask: Mask,
+    address_space: HashMap<usize, u64>,
+}
+
+impl Program {
+    fn apply(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Mask(mask) => {
+                self.mask = mask;
+            }
+            Instruction::Mem(address, value) => {
+                self.write(address, value);
+            }
+        }
+    }
+
+    fn apply_part_2(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Mask(mask) => {
+                self.mask = mask;
+            }
+            Instruction::Mem(address, value) => {
+                for address in self.mask.apply_address(address as u64) {
+                    self.write_part_2(address as usize, value);
+                }
+            }
+        }
+    }
+
+    fn write(&mut self, address: usize, value: u64) {
+        self.address_space.insert(address, self.mask.apply(value));
+    }
+
+    fn write_part_2(&mut self, address: usize, value: u64) {
+        self.address_space.insert(address, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floaters_1() {
+        let mask: Mask = "000000000000000000000000000000X1001X".parse().unwrap();
+        let addresses = mask.apply_address(42);
+        assert_eq!(addresses, vec![26, 27, 58, 59]);
+    }
+
+    #[test]
+    fn floaters_2() {
+        let mask: Mask = "00000000000000000000000000000000X0XX".parse().unwrap();
+        let addresses = mask.apply_address(26);
+        assert_eq!(addresses, vec![16, 17, 18, 19, 24, 25, 26, 27]);
+    }
+
+    #[test]
+    fn instruction_invalid() {
+        let err = "mem[8] = 1x".parse::<Instruction>().unwrap_err();
+        assert_eq!(err.column(), 11);
+
+        let err = "mask = 0000X2".parse::<Instruction>().unwrap_err();
+        assert_eq!(err.column(), 13);
+        assert_eq!(
+            err.kind(),
+            Some(&InstructionParseError::InvalidMaskCharacter('2'))
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+pub struct Day15 {
+    starting_numbers: Vec<usize>,
+}
+
+impl Solution<'_> for Day15 {
+    const DAY: u8 = 15;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let starting_numbers = input
+            .trim()
+            .split(',')
+            .map(|part| parse_number(input, part))
+            .collect::<Result<Vec<usize>, _>>()?;
+        Ok(Day15 { starting_numbers })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(nth_spoken(&self.starting_numbers, 2020).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(nth_spoken(&self.starting_numbers, 30000000).into())
+    }
+}
+
+/// The number spoken on turn `n` of the memory game.
+pub fn nth_spoken(starting_numbers: &[usize], n: usize) -> usize {
+    let mut last_turn = HashMap::new();
+    let mut current_turn = 1;
+    let mut last_spoken_age = 0;
+    let mut deque: VecDeque<usize> = starting_numbers.iter().copied().collect();
+    loop {
+        let value = if let Some(start_value) = deque.pop_front() {
+            start_value
+        } else {
+            last_spoken_age
+        };
+
+        let prev = last_turn.insert(value, current_turn);
+        last_spoken_age = match prev {
+            None => 0,
+            Some(prev) => current_turn - prev,
+        };
+
+        if current_turn == n {
+            break value;
+        }
+
+        current_turn += 1;
+    }
+}
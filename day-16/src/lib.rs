@@ -0,0 +1,293 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+pub struct Day16 {
+    ticket_fields: TicketFields,
+    ticket: Ticket,
+    tickets: Vec<Ticket>,
+}
+
+impl Solution<'_> for Day16 {
+    const DAY: u8 = 16;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let missing = |section| ParseError::at_end(input, NotesParseError::MissingSection(section));
+
+        let mut parts = input.split("\n\n");
+        let first_part = parts.next().ok_or_else(|| missing("ticket fields"))?;
+        let ticket_fields = first_part
+            .parse::<TicketFields>()
+            .map_err(|err| err.within(input, first_part))?;
+
+        let second_part = parts.next().ok_or_else(|| missing("your ticket"))?;
+        let after_header = &second_part[(second_part
+            .find('\n')
+            .ok_or_else(|| missing("your ticket"))?
+            + 1)..];
+        let ticket = after_header
+            .parse::<Ticket>()
+            .map_err(|err| err.within(input, after_header))?;
+
+        let third_part = parts.next().ok_or_else(|| missing("nearby tickets"))?;
+        let tickets = third_part
+            .trim()
+            .lines()
+            .skip(1)
+            .map(|line| {
+                line.parse::<Ticket>()
+                    .map_err(|err| err.within(input, line))
+            })
+            .collect::<Result<Vec<Ticket>, _>>()?;
+
+        Ok(Day16 {
+            ticket_fields,
+            ticket,
+            tickets,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut invalid_sum = 0_usize;
+        for ticket in &self.tickets {
+            for value in &ticket.0 {
+                if !self.ticket_fields.valid(*value) {
+                    invalid_sum += value;
+                }
+            }
+        }
+        Ok(invalid_sum.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let ticket_fields = &self.ticket_fields;
+        let valid_tickets = self
+            .tickets
+            .iter()
+            .filter(|ticket| ticket.0.iter().all(|value| ticket_fields.valid(*value)));
+
+        let mut possible_positions = PossiblePositions::new(ticket_fields, self.ticket.0.len());
+        for ticket in valid_tickets {
+            for (idx, ticket_value) in ticket.0.iter().enumerate() {
+                for (ticket_field_name, ticket_field) in &ticket_fields.0 {
+                    if !ticket_field.valid(*ticket_value) {
+                        possible_positions.remove_possibility(ticket_field_name, idx);
+                    }
+                }
+            }
+        }
+        let mut product = 1_usize;
+        for (field, positions) in possible_positions.0 {
+            if field.contains("departure") {
+                if let Some(position) = positions.the_remaining() {
+                    let value = self.ticket.0[position];
+                    product *= value;
+                }
+            }
+        }
+        Ok(product.into())
+    }
+}
+
+#[derive(Debug)]
+struct Ticket(Vec<usize>);
+
+impl std::str::FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let vec = s
+            .trim()
+            .split(',')
+            .map(|value| parse_number(s, value))
+            .collect::<Result<Vec<usize>, _>>()?;
+        Ok(Self(vec))
+    }
+}
+
+#[derive(Debug)]
+struct TicketField {
+    min0: usize,
+    max0: usize,
+    min1: usize,
+    max1: usize,
+}
+
+impl TicketField {
+    fn valid(&self, v: usize) -> bool {
+        (self.min0 <= v && v <= self.max0) || (self.min1 <= v && v <= self.max1)
+    }
+}
+
+impl std::str::FromStr for TicketField {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expected = |at: &str, what| ParseError::at(s, at, NotesParseError::Expected(what));
+
+        let rest = s;
+        let first_dash = rest.find('-').ok_or_else(|| expected(rest, "-"))?;
+        let min0 = &rest[0..first_dash];
+        let rest = &rest[(first_dash + 1)..];
+        let or_text = rest.find(" or ").ok_or_else(|| expected(rest, "or"))?;
+        let max0 = &rest[0..or_text];
+        let rest = &rest[(or_text + 4)..];
+        let second_dash = rest.find('-').ok_or_else(|| expected(rest, "-"))?;
+        let min1 = &rest[0..second_dash];
+        let max1 = &rest[(second_dash + 1)..];
+
+        Ok(Self {
+            min0: parse_number(s, min0)?,
+            max0: parse_number(s, max0)?,
+            min1: parse_number(s, min1)?,
+            max1: parse_number(s, max1)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct TicketFields(HashMap<String, TicketField>);
+
+impl TicketFields {
+    fn valid(&self, v: usize) -> bool {
+        for field in self.0.values() {
+            if field.valid(v) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl std::str::FromStr for TicketFields {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hashmap = HashMap::new();
+        let lines = s.trim().split('\n');
+        for line in lines {
+            let line = line.trim();
+            let colon = line
+                .find(':')
+                .ok_or_else(|| ParseError::at(s, line, NotesParseError::Expected(":")))?;
+            let name = line[0..colon].to_string();
+            let field = line[(colon + 1)..].trim_start();
+            let field = field
+                .parse()
+                .map_err(|err: ParseError| err.within(s, field))?;
+            hashmap.insert(name, field);
+        }
+        Ok(Self(hashmap))
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum NotesParseError {
+    #[error("Missing {0}")]
+    MissingSection(&'static str),
+    #[error("Expected \"{0}\"")]
+    Expected(&'static str),
+}
+
+#[derive(Debug)]
+struct PossiblePositions(HashMap<String, Positions>);
+
+impl PossiblePositions {
+    fn new(fields: &TicketFields, len: usize) -> Self {
+        let mut hashmap = HashMap::new();
+        for field in fields.0.keys() {
+            hashmap.insert(field.to_string(), Positions::from_len(len));
+        }
+        Self(hashmap)
+    }
+
+    fn remove_possibility(&mut self, field: &str, position: usize) {
+        let remove_rest = if let Some(positions) = self.0.get_mut(field) {
+            if positions.remove(position) {
+                positions.the_remaining()
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let mut remove_stack: VecDeque<(String, usize)> = VecDeque::new();
+        if let Some(remaining) = remove_rest {
+            remove_stack.push_back((field.to_string(), remaining));
+        }
+
+        while let Some((field, position)) = remove_stack.pop_front() {
+            for (field2, positions) in self.0.iter_mut() {
+                if field2 == &field {
+                    continue;
+                }
+
+                if positions.remove(position) {
+                    if let Some(remaining) = positions.the_remaining() {
+                        remove_stack.push_back((field2.to_string(), remaining));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Positions(u32);
+
+impl Positions {
+    fn from_len(len: usize) -> Self {
+        let val = (1 << len) - 1;
+        Self(val as u32)
+    }
+
+    fn remove(&mut self, v: usize) -> bool {
+        let mask = (1 << v) as u32;
+        if self.0 & mask > 0 {
+            self.0 &= !mask;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn contains(&self, v: usize) -> bool {
+        let mask = (1 << v) as u32;
+        self.0 & mask > 0
+    }
+
+    fn the_remaining(&self) -> Option<usize> {
+        if self.0.count_ones() != 1 {
+            None
+        } else {
+            let mut r = 0;
+            let mut n = self.0;
+            while n != 1 {
+                r += 1;
+                n >>= 1;
+            }
+            Some(r)
+        }
+    }
+}
+
+impl std::fmt::Debug for Positions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut is_first = true;
+        write!(f, "{{ ")?;
+        for i in 0..32 {
+            if self.contains(i) {
+                if is_first {
+                    is_first = false;
+                    write!(f, "{}", i)?;
+                } else {
+                    write!(f, ", {}", i)?;
+                }
+            }
+        }
+        write!(f, " }}")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+mod expr;
+mod lexer;
+mod parser;
+
+use aoc_core::{Answer, ParseError, Result, Solution};
+use parser::{Parser, ParserPartB};
+
+pub use parser::ExpressionParseError;
+
+pub struct Day18<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Day18<'a> {
+    const DAY: u8 = 18;
+
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let lines = input.lines().map(|line| line.trim()).collect::<Vec<_>>();
+        for line in &lines {
+            parser::check(line).map_err(|err| err.within(input, line))?;
+        }
+        Ok(Day18 { lines })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut sum = 0;
+        let parser = Parser;
+        for line in &self.lines {
+            let expr = parser.parse(line);
+            let val = expr.evaluate();
+            sum += val;
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut sum = 0;
+        let parser = ParserPartB;
+        for line in &self.lines {
+            let expr = parser.parse(line);
+            let val = expr.evaluate();
+            sum += val;
+        }
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let parser = Parser;
+        assert_eq!(parser.parse("1 + 2 * 3 + 4 * 5 + 6").evaluate(), 71);
+        assert_eq!(parser.parse("1 + (2 * 3) + (4 * (5 + 6))").evaluate(), 51);
+        assert_eq!(parser.parse("2 * 3 + (4 * 5)").evaluate(), 26);
+        assert_eq!(parser.parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").evaluate(), 437);
+        assert_eq!(
+            parser
+                .parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")
+                .evaluate(),
+            12240
+        );
+        assert_eq!(
+            parser
+                .parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")
+                .evaluate(),
+            13632
+        );
+    }
+
+    #[test]
+    fn examples_part_b() {
+        let parser = ParserPartB;
+        assert_eq!(parser.parse("1 + 2 * 3 + 4 * 5 + 6").evaluate(), 231);
+        assert_eq!(parser.parse("1 + (2 * 3) + (4 * (5 + 6))").evaluate(), 51);
+        assert_eq!(parser.parse("2 * 3 + (4 * 5)").evaluate(), 46);
+        assert_eq!(parser.parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").evaluate(), 1445);
+        assert_eq!(
+            parser
+                .parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")
+                .evaluate(),
+            669060
+        );
+        assert_eq!(
+            parser
+                .parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")
+                .evaluate(),
+            23340
+        );
+    }
+}
@@ -1,5 +1,77 @@
 use crate::expr::Expr;
 use crate::lexer::Lexer;
+use aoc_core::{parse_number, ParseError};
+
+/// Check that a line is a well-formed expression, which both parsers can then parse without
+/// failing.
+pub fn check(line: &str) -> Result<(), ParseError> {
+    let error = |i: usize, kind| Err(ParseError::at(line, &line[i..], kind));
+
+    let mut expect_operand = true;
+    let mut depth = 0_usize;
+    let mut chars = line.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            ' ' => {}
+            '0'..='9' => {
+                if !expect_operand {
+                    return error(i, ExpressionParseError::ExpectedOperator);
+                }
+                let mut end = i + 1;
+                while let Some((j, '0'..='9')) = chars.peek() {
+                    end = j + 1;
+                    chars.next();
+                }
+                parse_number::<i64>(line, &line[i..end])?;
+                expect_operand = false;
+            }
+            '(' => {
+                if !expect_operand {
+                    return error(i, ExpressionParseError::ExpectedOperator);
+                }
+                depth += 1;
+            }
+            ')' => {
+                if expect_operand {
+                    return error(i, ExpressionParseError::ExpectedOperand);
+                }
+                if depth == 0 {
+                    return error(i, ExpressionParseError::UnmatchedParenthesis);
+                }
+                depth -= 1;
+            }
+            '+' | '*' => {
+                if expect_operand {
+                    return error(i, ExpressionParseError::ExpectedOperand);
+                }
+                expect_operand = true;
+            }
+            ch => return error(i, ExpressionParseError::InvalidCharacter(ch)),
+        }
+    }
+
+    if expect_operand {
+        error(line.len(), ExpressionParseError::ExpectedOperand)
+    } else if depth > 0 {
+        error(line.len(), ExpressionParseError::UnclosedParenthesis)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum ExpressionParseError {
+    #[error("Invalid character: {0}")]
+    InvalidCharacter(char),
+    #[error("Expected a number or an opening parenthesis")]
+    ExpectedOperand,
+    #[error("Expected an operator or a closing parenthesis")]
+    ExpectedOperator,
+    #[error("Closing parenthesis without an opening one")]
+    UnmatchedParenthesis,
+    #[error("Unclosed parenthesis")]
+    UnclosedParenthesis,
+}
 
 pub struct Parser;
 
@@ -29,7 +101,7 @@ impl Parser {
                 n => {
                     let number = lexeme
                         .parse::<i64>()
-                        .expect(&format!("Unexpected lexeme '{}'", n));
+                        .unwrap_or_else(|_| panic!("Unexpected lexeme '{}'", n));
                     let expr = Expr::number(number);
                     stack.push_back(StackItem::Expr(expr));
                 }
@@ -113,7 +185,7 @@ impl ParserPartB {
                 n => {
                     let number = lexeme
                         .parse::<i64>()
-                        .expect(&format!("Unexpected lexeme '{}'", n));
+                        .unwrap_or_else(|_| panic!("Unexpected lexeme '{}'", n));
                     let expr = Expr::number(number);
                     stack.push_back(StackItem::Expr(expr));
                 }
@@ -168,6 +240,25 @@ impl StackItem {
 mod tests {
     use super::*;
 
+    #[test]
+    fn check_invalid() {
+        assert!(check("1 + (2 * 3)").is_ok());
+
+        let err = check("1 + (2 * 3").unwrap_err();
+        assert_eq!(err.column(), 11);
+        assert_eq!(err.kind(), Some(&ExpressionParseError::UnclosedParenthesis));
+
+        let err = check("1 + 2 3").unwrap_err();
+        assert_eq!(err.column(), 7);
+        assert_eq!(err.kind(), Some(&ExpressionParseError::ExpectedOperator));
+
+        let err = check("1 - 2").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            Some(&ExpressionParseError::InvalidCharacter('-'))
+        );
+    }
+
     #[test]
     fn first_example() {
         let input = "1 + 2 * 3 + 4 * 5 + 6";
@@ -0,0 +1,322 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Day19<'a> {
+    rules: Rules<'a>,
+    messages: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Day19<'a> {
+    const DAY: u8 = 19;
+
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut iter = input.trim().split("\n\n");
+        let rules = iter.next().unwrap_or_default();
+        let messages = iter
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, RuleParseError::MissingMessages))?;
+
+        let rules = Rules::parse(rules).map_err(|err| err.within(input, rules))?;
+        let messages = messages.lines().collect();
+        Ok(Day19 { rules, messages })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let r = self
+            .messages
+            .iter()
+            .filter(|message| self.rules.is_match(message))
+            .count();
+        Ok(r.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut rules = self.rules.clone();
+        rules.r.insert(8, Rule::parse("42 | 42 8")?);
+        rules.r.insert(11, Rule::parse("42 31 | 42 11 31")?);
+
+        let r = self
+            .messages
+            .iter()
+            .filter(|message| rules.is_match(message))
+            .count();
+        Ok(r.into())
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Rule<'a> {
+    Terminal(&'a str),
+    Ref(usize),
+    Cons(Box<Rule<'a>>, Box<Rule<'a>>),
+    Alt(Box<Rule<'a>>, Box<Rule<'a>>),
+}
+
+impl<'a> Rule<'a> {
+    fn consume(&self, rules: &Rules, input: &str) -> Vec<usize> {
+        let consumed = match self {
+            Rule::Terminal(t) => {
+                if t.len() > input.len() {
+                    vec![]
+                } else if *t == &input[0..t.len()] {
+                    vec![t.len()]
+                } else {
+                    vec![]
+                }
+            }
+            Rule::Ref(r) => {
+                if let Some(rule) = rules.get_rule(*r) {
+                    rule.consume(rules, input)
+                } else {
+                    vec![]
+                }
+            }
+            Rule::Cons(r1, r2) => {
+                let mut possibilities = Vec::new();
+
+                for r1_consumed in r1.consume(rules, input) {
+                    for r2_consumed in r2.consume(rules, &input[r1_consumed..]) {
+                        possibilities.push(r1_consumed + r2_consumed);
+                    }
+                }
+
+                possibilities.sort();
+                possibilities.dedup();
+                possibilities
+            }
+            Rule::Alt(r1, r2) => {
+                let mut r1 = r1.consume(rules, input);
+                let mut r2 = r2.consume(rules, input);
+                r1.append(&mut r2);
+                r1.sort();
+                r1.dedup();
+                r1
+            }
+        };
+        consumed
+    }
+}
+
+impl<'a> Rule<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(ParseError::at_end(s, RuleParseError::EmptyRule));
+        }
+        if let Some(rest) = trimmed.strip_prefix('"') {
+            let idx = rest
+                .find('"')
+                .ok_or_else(|| ParseError::at_end(s, RuleParseError::UnclosedQuote))?;
+            let terminal = &rest[..idx];
+            return Ok(Rule::Terminal(terminal));
+        }
+
+        fn parse_seq<'a>(s: &str, seq: &str) -> Result<Rule<'a>, ParseError> {
+            let mut refs = std::collections::VecDeque::new();
+            for n in seq.split_whitespace() {
+                let r = Rule::Ref(parse_number(s, n)?);
+                refs.push_back(r);
+            }
+
+            let mut r = refs
+                .pop_back()
+                .ok_or_else(|| ParseError::at(s, seq, RuleParseError::EmptyRule))?;
+            while let Some(n) = refs.pop_back() {
+                r = Rule::Cons(Box::new(n), Box::new(r));
+            }
+
+            Ok(r)
+        }
+
+        if let Some(idx) = trimmed.find('|') {
+            let alt1 = &trimmed[0..idx];
+            let alt2 = &trimmed[(idx + 1)..];
+            let alt1 = parse_seq(s, alt1)?;
+            let alt2 = parse_seq(s, alt2)?;
+            Ok(Rule::Alt(Box::new(alt1), Box::new(alt2)))
+        } else {
+            parse_seq(s, trimmed)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum RuleParseError {
+    #[error("Missing messages")]
+    MissingMessages,
+    #[error("Missing rule")]
+    MissingRule,
+    #[error("Empty rule")]
+    EmptyRule,
+    #[error("Couldn't find second quotation mark")]
+    UnclosedQuote,
+}
+
+#[derive(Debug, Clone)]
+struct Rules<'a> {
+    r: HashMap<usize, Rule<'a>>,
+}
+
+impl<'a> Rules<'a> {
+    fn is_match(&self, input: &str) -> bool {
+        let rule = Rule::Ref(0);
+
+        let possibilities = rule.consume(self, input);
+        possibilities.contains(&input.len())
+    }
+
+    fn get_rule(&self, idx: usize) -> Option<&Rule<'_>> {
+        self.r.get(&idx)
+    }
+}
+
+impl<'a> Rules<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let mut map = HashMap::new();
+        for line in s.trim().lines() {
+            let colon = line.find(": ").ok_or_else(|| {
+                ParseError::at(s, &line[line.len()..], RuleParseError::MissingRule)
+            })?;
+            let rule_number = parse_number(s, &line[..colon])?;
+            let rule = &line[(colon + 2)..];
+            let rule = Rule::parse(rule).map_err(|err| err.within(s, rule))?;
+            map.insert(rule_number, rule);
+        }
+
+        Ok(Rules { r: map })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        let mut map = HashMap::new();
+        map.insert(
+            0,
+            Rule::Cons(Box::new(Rule::Ref(1)), Box::new(Rule::Ref(2))),
+        );
+        map.insert(1, Rule::Terminal("a"));
+        map.insert(
+            2,
+            Rule::Alt(
+                Box::new(Rule::Cons(Box::new(Rule::Ref(1)), Box::new(Rule::Ref(3)))),
+                Box::new(Rule::Cons(Box::new(Rule::Ref(3)), Box::new(Rule::Ref(1)))),
+            ),
+        );
+        map.insert(3, Rule::Terminal("b"));
+
+        let rules = Rules { r: map };
+
+        assert!(rules.is_match("aab"));
+        assert!(rules.is_match("aba"));
+
+        assert!(!rules.is_match("aaa"));
+        assert!(!rules.is_match("abb"));
+    }
+
+    #[test]
+    fn example_2() {
+        /*
+        0: 4 1 5
+        1: 2 3 | 3 2
+        2: 4 4 | 5 5
+        3: 4 5 | 5 4
+        4: "a"
+        5: "b"
+
+        ababbb
+        bababa
+        abbbab
+        aaabbb
+        aaaabbb
+        */
+
+        fn alt_seq(a: usize, b: usize, c: usize, d: usize) -> Rule<'static> {
+            Rule::Alt(
+                Box::new(Rule::Cons(Box::new(Rule::Ref(a)), Box::new(Rule::Ref(b)))),
+                Box::new(Rule::Cons(Box::new(Rule::Ref(c)), Box::new(Rule::Ref(d)))),
+            )
+        }
+
+        let mut map = HashMap::new();
+        map.insert(
+            0,
+            Rule::Cons(
+                Box::new(Rule::Ref(4)),
+                Box::new(Rule::Cons(Box::new(Rule::Ref(1)), Box::new(Rule::Ref(5)))),
+            ),
+        );
+        map.insert(1, alt_seq(2, 3, 3, 2));
+        map.insert(2, alt_seq(4, 4, 5, 5));
+        map.insert(3, alt_seq(4, 5, 5, 4));
+        map.insert(4, Rule::Terminal("a"));
+        map.insert(5, Rule::Terminal("b"));
+
+        let rules = Rules { r: map };
+
+        assert!(rules.is_match("ababbb"));
+        assert!(!rules.is_match("bababa"));
+        assert!(rules.is_match("abbbab"));
+        assert!(!rules.is_match("aaabbb"));
+        assert!(!rules.is_match("aaaabbb"));
+        assert!(!rules.is_match("ab"));
+    }
+
+    #[test]
+    fn part_b_example() {
+        let rules = r#"
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+8: 42 | 42 8
+11: 42 31 | 42 11 31
+"#;
+        let rules = Rules::parse(rules).unwrap();
+
+        assert!(!rules.is_match("abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa"));
+        assert!(rules.is_match("bbabbbbaabaabba"));
+        assert!(rules.is_match("babbbbaabbbbbabbbbbbaabaaabaaa"));
+        assert!(rules.is_match("aaabbbbbbaaaabaababaabababbabaaabbababababaaa"));
+        assert!(rules.is_match("bbbbbbbaaaabbbbaaabbabaaa"));
+        assert!(rules.is_match("bbbababbbbaaaaaaaabbababaaababaabab"));
+        assert!(rules.is_match("ababaaaaaabaaab"));
+        assert!(rules.is_match("ababaaaaabbbaba"));
+        assert!(rules.is_match("baabbaaaabbaaaababbaababb"));
+        assert!(rules.is_match("abbbbabbbbaaaababbbbbbaaaababb"));
+        assert!(rules.is_match("aaaaabbaabaaaaababaa"));
+        assert!(!rules.is_match("aaaabbaaaabbaaa"));
+        assert!(rules.is_match("aaaabbaabbaaaaaaabbbabbbaaabbaabaaa"));
+        assert!(!rules.is_match("babaaabbbaaabaababbaabababaaab"));
+        assert!(rules.is_match("aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"));
+    }
+}
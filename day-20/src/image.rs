@@ -1,60 +1,81 @@
 use super::IdentifiedTile;
 use super::Tile;
 use super::TileIndex;
+use aoc_core::Result;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::mem::{self, MaybeUninit};
 
 #[derive(Clone)]
 pub struct Image {
-    tiles: [ImageTile; 144],
+    rows: usize,
+    cols: usize,
+    tiles: Vec<ImageTile>,
 }
 
 impl Image {
-    pub(crate) fn build(
+    /// Put together an image `rows` tiles high and `cols` tiles wide, or say where a tile is
+    /// missing.
+    pub fn build(
+        rows: usize,
+        cols: usize,
         solution: HashMap<(usize, usize), TileIndex>,
         tiles: &[IdentifiedTile],
-    ) -> Image {
-        let mut r = Vec::with_capacity(144);
-
-        for y in 0..12 {
-            for x in 0..12 {
-                if let Some(tile_idx) = solution.get(&(x, y)) {
-                    let tile = tiles
-                        .into_iter()
-                        .find(|&&tile| tile.id == tile_idx.id)
-                        .unwrap();
-                    let tile = tile.tile.transform(tile_idx.transform);
-                    let image_tile = ImageTile::from(tile);
-                    r.push(image_tile)
-                } else {
-                    panic!("No tile at {},{}", x, y);
+    ) -> Result<Image> {
+        let mut r = Vec::with_capacity(rows * cols);
+
+        for y in 0..rows {
+            for x in 0..cols {
+                let tile = solution.get(&(x, y)).and_then(|tile_idx| {
+                    let tile = tiles.iter().find(|&&tile| tile.id == tile_idx.id)?;
+                    Some(tile.tile.transform(tile_idx.transform))
+                });
+                match tile {
+                    Some(tile) => r.push(ImageTile::from(tile)),
+                    None => return Err(format!("No tile at {},{}", x, y).into()),
                 }
             }
         }
 
-        let mut data: [MaybeUninit<ImageTile>; 144] =
-            unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..144 {
-            data[i] = MaybeUninit::new(r[i]);
-        }
-        let tiles = unsafe { mem::transmute::<_, [ImageTile; 144]>(data) };
-
-        Self { tiles }
+        Ok(Self {
+            rows,
+            cols,
+            tiles: r,
+        })
     }
 }
 
 impl Image {
-    pub const SIZE: usize = 12 * 8;
+    /// The width of the image in pixels, without the tiles' borders.
+    pub fn width(&self) -> usize {
+        self.cols * 8
+    }
+
+    /// The height of the image in pixels, without the tiles' borders.
+    pub fn height(&self) -> usize {
+        self.rows * 8
+    }
 
     pub fn get(&self, x: usize, y: usize) -> bool {
         let tile_x = x / 8;
         let tile_y = y / 8;
-        let tile_idx = tile_y * 12 + tile_x;
+        let tile_idx = tile_y * self.cols + tile_x;
         let tile = &self.tiles[tile_idx];
         tile.get(x % 8, y % 8)
     }
 
+    /// The number of set pixels, whether or not they're part of a sea monster.
+    pub fn count_waves(&self) -> usize {
+        let mut waves = 0;
+        for x in 0..self.width() {
+            for y in 0..self.height() {
+                if self.get(x, y) {
+                    waves += 1;
+                }
+            }
+        }
+        waves
+    }
+
     pub fn write<T>(
         &self,
         writer: &mut T,
@@ -65,16 +86,18 @@ impl Image {
     {
         write!(
             writer,
-            r##"<svg version="1.0" xmlns="http://www.w3.org/2000/svg" width="100%" height="100%" viewBox="0 0 {size} {size}">"##,
-            size = Self::SIZE
+            r##"<svg version="1.0" xmlns="http://www.w3.org/2000/svg" width="100%" height="100%" viewBox="0 0 {width} {height}">"##,
+            width = self.width(),
+            height = self.height()
         )?;
         write!(
             writer,
-            r##"  <rect x="0" y="0" width="{size}" height="{size}" fill="#000088"/>"##,
-            size = Self::SIZE
+            r##"  <rect x="0" y="0" width="{width}" height="{height}" fill="#000088"/>"##,
+            width = self.width(),
+            height = self.height()
         )?;
-        for x in 0..Self::SIZE {
-            for y in 0..Self::SIZE {
+        for x in 0..self.width() {
+            for y in 0..self.height() {
                 if self.get(x, y) {
                     write!(
                         writer,
@@ -99,7 +122,7 @@ impl Image {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 struct ImageTile {
     inner: u64,
 }
@@ -135,3 +158,44 @@ impl ImageTile {
         (self.inner & (1 << idx)) > 0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Transform;
+
+    #[test]
+    fn any_number_of_tiles() {
+        let rows = [".........."; 10];
+        let mut rows: Vec<String> = rows.iter().map(|row| row.to_string()).collect();
+        rows[1].replace_range(1..2, "#");
+        rows[2].replace_range(2..3, "#");
+        let tile = |id| format!("Tile {}:\n{}", id, rows.join("\n"));
+        let tiles = [tile(1).parse().unwrap(), tile(2).parse().unwrap()];
+        let solution: HashMap<_, _> = vec![
+            (
+                (0, 0),
+                TileIndex {
+                    id: 1,
+                    transform: Transform::R0,
+                },
+            ),
+            (
+                (1, 0),
+                TileIndex {
+                    id: 2,
+                    transform: Transform::R0,
+                },
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let image = Image::build(1, 2, solution.clone(), &tiles).unwrap();
+        assert_eq!((image.width(), image.height()), (16, 8));
+        assert_eq!(image.count_waves(), 4);
+        assert!(image.get(0, 0) && image.get(1, 1) && image.get(8, 0));
+
+        assert!(Image::build(2, 2, solution, &tiles).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+mod image;
+mod tile;
+mod transform;
+
+pub use image::*;
+pub use tile::*;
+pub use transform::*;
+
+use aoc_core::{parse_number, Answer, ParseError, Result};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Day20 {
+    tiles: Vec<IdentifiedTile>,
+}
+
+impl Day20 {
+    pub fn tiles(&self) -> &[IdentifiedTile] {
+        &self.tiles
+    }
+}
+
+impl aoc_core::Solution<'_> for Day20 {
+    const DAY: u8 = 20;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = input
+            .trim()
+            .split("\n\n")
+            .map(|tile| {
+                tile.parse::<IdentifiedTile>()
+                    .map_err(|err| err.within(input, tile))
+            })
+            .collect::<Result<Vec<IdentifiedTile>, _>>()?;
+        Ok(Day20 { tiles })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let (rows, cols) = dimensions(&self.tiles)?;
+        let solution = arrangements(&self.tiles)?
+            .into_iter()
+            .next()
+            .ok_or("The tiles can't be arranged")?;
+        let corners = solution.corners(rows, cols);
+        Ok(corners.iter().map(|tile| tile.id).product::<usize>().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let (rows, cols) = dimensions(&self.tiles)?;
+        for solution in arrangements(&self.tiles)? {
+            let image = Image::build(rows, cols, solution.tiles, &self.tiles)?;
+            let (monster_count, monsters) = find_monsters(&image);
+            if monster_count > 0 {
+                return Ok((image.count_waves() - monsters.len()).into());
+            }
+        }
+        Err("No arrangement has any sea monsters".into())
+    }
+}
+
+/// The number of rows and columns of tiles in the full image.
+pub fn dimensions(tiles: &[IdentifiedTile]) -> Result<(usize, usize)> {
+    let dimensions = match tiles.len() {
+        1 => (1, 1),
+        4 => (2, 2),
+        9 => (3, 3),
+        16 => (4, 4),
+        25 => (5, 5),
+        36 => (6, 6),
+        49 => (7, 7),
+        64 => (8, 8),
+        81 => (9, 9),
+        100 => (10, 10),
+        121 => (11, 11),
+        144 => (12, 12),
+        _ => return Err("Get lost".into()),
+    };
+    Ok(dimensions)
+}
+
+/// Every way the tiles can be arranged so that all of their borders line up.
+pub fn arrangements(tiles: &[IdentifiedTile]) -> Result<Vec<Solution>> {
+    let (rows, cols) = dimensions(tiles)?;
+
+    let mut borders: HashMap<TileIndex, (TileBorder, TileBorder, TileBorder, TileBorder)> =
+        HashMap::new();
+    let mut all: HashSet<TileIndex> = HashSet::new();
+    for tile in tiles {
+        for transform in Transform::all() {
+            let id = tile.id;
+            let tile = tile.tile.transform(transform);
+            let idx = TileIndex { id, transform };
+            borders.insert(
+                idx,
+                (
+                    tile.top_border(),
+                    tile.right_border(),
+                    tile.bottom_border(),
+                    tile.left_border(),
+                ),
+            );
+            all.insert(idx);
+        }
+    }
+
+    Ok(solve(
+        rows,
+        cols,
+        0,
+        0,
+        Solution::default(),
+        HashSet::new(),
+        all,
+        &borders,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn solve(
+    rows: usize,
+    cols: usize,
+    x: usize,
+    y: usize,
+    current: Solution,
+    used: HashSet<usize>,
+    remaining: HashSet<TileIndex>,
+    borders: &HashMap<TileIndex, (TileBorder, TileBorder, TileBorder, TileBorder)>,
+) -> Vec<Solution> {
+    let mut possibilities = Vec::new();
+
+    let target_left_border: Option<TileBorder> = if x > 0 {
+        let to_the_left = current.tiles.get(&(x - 1, y)).unwrap();
+        let right_border = borders.get(to_the_left).unwrap().1;
+        Some(right_border)
+    } else {
+        None
+    };
+
+    let target_top_border: Option<TileBorder> = if y > 0 {
+        let to_the_top = current.tiles.get(&(x, y - 1)).unwrap();
+        let bottom_border = borders.get(to_the_top).unwrap().2;
+        Some(bottom_border)
+    } else {
+        None
+    };
+
+    for index in &remaining {
+        if used.contains(&index.id) {
+            // println!("Skipping {:?} because {} has already been used", index, index.id);
+            continue;
+        }
+
+        // println!("Trying {:?}", index);
+        let (top_border, _right_border, _bottom_border, left_border) = borders.get(index).unwrap();
+        if let Some(target_left_border) = target_left_border {
+            if *left_border != target_left_border {
+                // println!("Nope. Left border doesn't match.");
+                continue;
+            }
+        }
+        if let Some(target_top_border) = target_top_border {
+            if *top_border != target_top_border {
+                // println!("Nope. Top border doesn't match.");
+                continue;
+            }
+        }
+        // println!("Yep!");
+        possibilities.push(index);
+    }
+
+    let mut results = Vec::new();
+
+    for possibility in possibilities {
+        if x == cols - 1 && y == rows - 1 {
+            let mut current = current.clone();
+            current.tiles.insert((x, y), *possibility);
+            results.push(current);
+        } else {
+            // Recusively solve!
+            let (new_x, new_y) = if y == rows - 1 {
+                (x + 1, 0)
+            } else {
+                (x, y + 1)
+            };
+            let mut current = current.clone();
+            current.tiles.insert((x, y), *possibility);
+            let mut used = used.clone();
+            used.insert(possibility.id);
+            let remaining = remaining.clone();
+            let mut solutions = solve(rows, cols, new_x, new_y, current, used, remaining, borders);
+            results.append(&mut solutions);
+        }
+    }
+
+    results
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Solution {
+    pub tiles: HashMap<(usize, usize), TileIndex>,
+}
+
+impl Solution {
+    /// The top left, top right, bottom left, and bottom right tiles.
+    pub fn corners(&self, rows: usize, cols: usize) -> [TileIndex; 4] {
+        [
+            self.tiles[&(0, 0)],
+            self.tiles[&(cols - 1, 0)],
+            self.tiles[&(0, rows - 1)],
+            self.tiles[&(cols - 1, rows - 1)],
+        ]
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct IdentifiedTile {
+    pub id: usize,
+    pub tile: Tile,
+}
+
+impl std::str::FromStr for IdentifiedTile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '\n');
+        let first_line = parts.next().unwrap_or_default();
+        let rest = parts.next().unwrap_or_default();
+
+        let missing_header = || ParseError::at(s, first_line, TileParseError::MissingHeader);
+        let id = first_line
+            .strip_prefix("Tile ")
+            .and_then(|id| id.strip_suffix(':'))
+            .ok_or_else(missing_header)?;
+        let id = parse_number(s, id)?;
+        let tile = rest.parse::<Tile>().map_err(|err| err.within(s, rest))?;
+
+        Ok(Self { id, tile })
+    }
+}
+
+impl std::fmt::Debug for IdentifiedTile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tile {}:\n{:?}", self.id, self.tile)
+    }
+}
+
+#[derive(Clone, Copy, Hash, Debug, Eq, PartialEq)]
+pub struct TileIndex {
+    pub id: usize,
+    pub transform: transform::Transform,
+}
+
+pub fn find_monsters(image: &Image) -> (usize, HashSet<(usize, usize)>) {
+    /// 0123456789_123456789
+    /// ..................#.
+    /// #....##....##....###
+    /// .#..#..#..#..#..#...
+    const OFFSETS: [(usize, usize); 15] = [
+        (18, 0),
+        (0, 1),
+        (5, 1),
+        (6, 1),
+        (11, 1),
+        (12, 1),
+        (17, 1),
+        (18, 1),
+        (19, 1),
+        (1, 2),
+        (4, 2),
+        (7, 2),
+        (10, 2),
+        (13, 2),
+        (16, 2),
+    ];
+    const MONSTER_WIDTH: usize = 20;
+    const MONSTER_HEIGHT: usize = 3;
+
+    let mut matches = 0;
+    let mut coordinates = HashSet::new();
+    for x in 0..image.width().saturating_sub(MONSTER_WIDTH) {
+        for y in 0..image.height().saturating_sub(MONSTER_HEIGHT) {
+            let all_match = OFFSETS
+                .iter()
+                .all(|&(offset_x, offset_y)| image.get(offset_x + x, offset_y + y));
+            if all_match {
+                matches += 1;
+                for &(offset_x, offset_y) in OFFSETS.iter() {
+                    coordinates.insert((offset_x + x, offset_y + y));
+                }
+            }
+        }
+    }
+
+    (matches, coordinates)
+}
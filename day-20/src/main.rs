@@ -1,284 +1,51 @@
-mod image;
-mod tile;
-mod transform;
+use aoc_core::cli::{Cli, Format};
+use aoc_core::Report;
+use day_20::{find_monsters, Day20, Image};
 
-use image::*;
-use tile::*;
-use transform::*;
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(&[("svg", "DIR")]);
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
+    let (day, mut report) = Report::solve::<Day20>(&input)?;
 
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::io::Read;
+    let tiles = day.tiles();
+    let (rows, cols) = day_20::dimensions(tiles)?;
+    let all = day_20::arrangements(tiles)?;
 
-fn main() {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input).unwrap();
-
-    let tiles = input
-        .trim()
-        .split("\n\n")
-        .map(|line| line.parse())
-        .collect::<Result<Vec<IdentifiedTile>, _>>()
-        .unwrap();
-
-    let (rows, cols) = match tiles.len() {
-        1 => (1, 1),
-        4 => (2, 2),
-        9 => (3, 3),
-        16 => (4, 4),
-        25 => (5, 5),
-        36 => (6, 6),
-        49 => (7, 7),
-        64 => (8, 8),
-        81 => (9, 9),
-        100 => (10, 10),
-        121 => (11, 11),
-        144 => (12, 12),
-        _ => panic!("Get lost"),
-    };
-
-    let mut borders: HashMap<TileIndex, (TileBorder, TileBorder, TileBorder, TileBorder)> =
-        HashMap::new();
-    let mut all: HashSet<TileIndex> = HashSet::new();
-    for tile in &tiles {
-        for transform in Transform::all() {
-            let id = tile.id;
-            let tile = tile.tile.transform(transform);
-            let idx = TileIndex { id, transform };
-            borders.insert(
-                idx,
-                (
-                    tile.top_border(),
-                    tile.right_border(),
-                    tile.bottom_border(),
-                    tile.left_border(),
-                ),
-            );
-            all.insert(idx);
-        }
-    }
-
-    let all = solve(
-        rows,
-        cols,
-        0,
-        0,
-        Solution::default(),
-        HashSet::new(),
-        all.clone(),
-        &borders,
-    );
-    println!("{} solutions:", all.len());
+    let mut arrangements = Vec::with_capacity(all.len());
     for solution in all {
-        let tl = solution.tiles.get(&(0, 0)).unwrap();
-        let tr = solution.tiles.get(&(cols - 1, 0)).unwrap();
-        let bl = solution.tiles.get(&(0, rows - 1)).unwrap();
-        let br = solution.tiles.get(&(cols - 1, rows - 1)).unwrap();
-        println!(
-            "{} {} {} {} => {}",
-            tl.id,
-            tr.id,
-            bl.id,
-            br.id,
-            tl.id * tr.id * bl.id * br.id
-        );
+        let [tl, tr, bl, br] = solution.corners(rows, cols);
 
-        let name = format!("water-{}-{}-{}-{}.svg", tl.id, tr.id, bl.id, br.id);
-        let mut file = std::fs::File::create(name).unwrap();
-        let image = Image::build(solution.tiles, tiles.as_slice());
+        let image = Image::build(rows, cols, solution.tiles, tiles)?;
         let (monster_count, monsters) = find_monsters(&image);
-        println!("  {} monsters", monster_count);
-        image.write(&mut file, &monsters).unwrap();
-
-        if monster_count > 0 {
-            let mut waves = 0;
-            for x in 0..Image::SIZE {
-                for y in 0..Image::SIZE {
-                    if image.get(x, y) {
-                        waves += 1;
-                    }
-                }
-            }
-            waves -= monsters.len();
-            println!("{} waves", waves);
-        }
-    }
-}
-
-fn solve(
-    rows: usize,
-    cols: usize,
-    x: usize,
-    y: usize,
-    current: Solution,
-    used: HashSet<usize>,
-    remaining: HashSet<TileIndex>,
-    borders: &HashMap<TileIndex, (TileBorder, TileBorder, TileBorder, TileBorder)>,
-) -> Vec<Solution> {
-    let mut possibilities = Vec::new();
-
-    let target_left_border: Option<TileBorder>;
-    if x > 0 {
-        let to_the_left = current.tiles.get(&(x - 1, y)).unwrap();
-        let right_border = borders.get(to_the_left).unwrap().1;
-        target_left_border = Some(right_border);
-    } else {
-        target_left_border = None;
-    }
-
-    let target_top_border: Option<TileBorder>;
-    if y > 0 {
-        let to_the_top = current.tiles.get(&(x, y - 1)).unwrap();
-        let bottom_border = borders.get(to_the_top).unwrap().2;
-        target_top_border = Some(bottom_border);
-    } else {
-        target_top_border = None;
-    }
-
-    for index in &remaining {
-        if used.contains(&index.id) {
-            // println!("Skipping {:?} because {} has already been used", index, index.id);
-            continue;
+        if let Some(dir) = cli.flag("svg") {
+            let name = format!("water-{}-{}-{}-{}.svg", tl.id, tr.id, bl.id, br.id);
+            let mut file = std::fs::File::create(std::path::Path::new(dir).join(name))?;
+            image.write(&mut file, &monsters)?;
         }
 
-        // println!("Trying {:?}", index);
-        let (top_border, _right_border, _bottom_border, left_border) = borders.get(index).unwrap();
-        if let Some(target_left_border) = target_left_border {
-            if *left_border != target_left_border {
-                // println!("Nope. Left border doesn't match.");
-                continue;
-            }
-        }
-        if let Some(target_top_border) = target_top_border {
-            if *top_border != target_top_border {
-                // println!("Nope. Top border doesn't match.");
-                continue;
-            }
-        }
-        // println!("Yep!");
-        possibilities.push(index);
+        arrangements.push(([tl.id, tr.id, bl.id, br.id], monster_count));
     }
 
-    let mut results = Vec::new();
-
-    for possibility in possibilities {
-        if x == cols - 1 && y == rows - 1 {
-            let mut current = current.clone();
-            current.tiles.insert((x, y), *possibility);
-            results.push(current);
-        } else {
-            // Recusively solve!
-            let new_x;
-            let new_y;
-            if y == rows - 1 {
-                new_x = x + 1;
-                new_y = 0;
-            } else {
-                new_x = x;
-                new_y = y + 1;
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            println!("{} solutions:", arrangements.len());
+            for ([tl, tr, bl, br], monster_count) in arrangements {
+                println!("  {} {} {} {}", tl, tr, bl, br);
+                println!("    {} monsters", monster_count);
             }
-            let mut current = current.clone();
-            current.tiles.insert((x, y), *possibility);
-            let mut used = used.clone();
-            used.insert(possibility.id);
-            let remaining = remaining.clone();
-            let mut solutions = solve(rows, cols, new_x, new_y, current, used, remaining, borders);
-            results.append(&mut solutions);
         }
-    }
-
-    results
-}
-
-#[derive(Debug, Clone)]
-struct Solution {
-    tiles: HashMap<(usize, usize), TileIndex>,
-}
-
-impl Default for Solution {
-    fn default() -> Self {
-        Solution {
-            tiles: HashMap::new(),
-        }
-    }
-}
-
-#[derive(Clone, Copy)]
-struct IdentifiedTile {
-    id: usize,
-    tile: Tile,
-}
-
-impl std::str::FromStr for IdentifiedTile {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.splitn(2, '\n');
-        let first_line = parts.next().ok_or("Less than 2 lines")?;
-        let rest = parts.next().ok_or("Less than 2 lines")?;
-
-        let colon = first_line.find(':').ok_or("Missing colon")?;
-        let id = first_line[5..colon]
-            .parse()
-            .map_err(|_| "Failed to parse number")?;
-        let tile = rest.parse()?;
-
-        Ok(Self { id, tile })
-    }
-}
-
-impl std::fmt::Debug for IdentifiedTile {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Tile {}:\n{:?}", self.id, self.tile)
-    }
-}
-
-#[derive(Clone, Copy, Hash, Debug, Eq, PartialEq)]
-struct TileIndex {
-    id: usize,
-    transform: transform::Transform,
-}
-
-fn find_monsters(image: &Image) -> (usize, HashSet<(usize, usize)>) {
-    /// 0123456789_123456789
-    /// ..................#.
-    /// #....##....##....###
-    /// .#..#..#..#..#..#...
-    const OFFSETS: [(usize, usize); 15] = [
-        (18, 0),
-        (0, 1),
-        (5, 1),
-        (6, 1),
-        (11, 1),
-        (12, 1),
-        (17, 1),
-        (18, 1),
-        (19, 1),
-        (1, 2),
-        (4, 2),
-        (7, 2),
-        (10, 2),
-        (13, 2),
-        (16, 2),
-    ];
-    const MONSTER_WIDTH: usize = 20;
-    const MONSTER_HEIGHT: usize = 3;
-
-    let mut matches = 0;
-    let mut coordinates = HashSet::new();
-    for x in 0..(Image::SIZE - MONSTER_WIDTH) {
-        for y in 0..(Image::SIZE - MONSTER_HEIGHT) {
-            let all_match = OFFSETS
-                .iter()
-                .all(|&(offset_x, offset_y)| image.get(offset_x + x, offset_y + y));
-            if all_match {
-                matches += 1;
-                for &(offset_x, offset_y) in OFFSETS.iter() {
-                    coordinates.insert((offset_x + x, offset_y + y));
-                }
-            }
+        Format::Json => {
+            let arrangements: Vec<_> = arrangements
+                .into_iter()
+                .map(|(corners, monsters)| {
+                    serde_json::json!({ "corners": corners, "monsters": monsters })
+                })
+                .collect();
+            report.extra("arrangements", arrangements)?;
+            report.print(Format::Json)?;
         }
     }
 
-    (matches, coordinates)
+    Ok(())
 }
@@ -1,16 +1,11 @@
 use crate::transform::Transform;
+use aoc_core::ParseError;
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Default)]
 pub struct Tile {
     inner: u128,
 }
 
-impl Default for Tile {
-    fn default() -> Self {
-        Self { inner: 0 }
-    }
-}
-
 impl Tile {
     pub fn transform(&self, transform: Transform) -> Self {
         match transform {
@@ -107,24 +102,35 @@ impl Tile {
 }
 
 impl std::str::FromStr for Tile {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut tile = Tile::default();
         for (y, line) in s.trim().lines().enumerate() {
             if y >= 10 {
-                return Err("x >= 10");
+                return Err(ParseError::at(s, line, TileParseError::TooManyRows));
             }
 
-            for (x, ch) in line.trim().chars().enumerate() {
+            let line = line.trim();
+            for (x, (i, ch)) in line.char_indices().enumerate() {
                 if x >= 10 {
-                    return Err("x >= 10");
+                    return Err(ParseError::at(
+                        s,
+                        &line[i..],
+                        TileParseError::TooManyColumns,
+                    ));
                 }
 
                 match ch {
                     '.' => tile.set(x, y, false),
                     '#' => tile.set(x, y, true),
-                    _ => return Err("Invalid character"),
+                    _ => {
+                        return Err(ParseError::at(
+                            s,
+                            &line[i..],
+                            TileParseError::InvalidCharacter(ch),
+                        ))
+                    }
                 }
             }
         }
@@ -132,6 +138,18 @@ impl std::str::FromStr for Tile {
     }
 }
 
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum TileParseError {
+    #[error("Expected a \"Tile <id>:\" header")]
+    MissingHeader,
+    #[error("More than 10 rows")]
+    TooManyRows,
+    #[error("More than 10 columns")]
+    TooManyColumns,
+    #[error("Invalid character: {0}")]
+    InvalidCharacter(char),
+}
+
 impl std::fmt::Debug for Tile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..10 {
@@ -147,17 +165,11 @@ impl std::fmt::Debug for Tile {
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub struct TileBorder {
     inner: u16,
 }
 
-impl Default for TileBorder {
-    fn default() -> Self {
-        TileBorder { inner: 0 }
-    }
-}
-
 impl TileBorder {
     fn set(&mut self, x: usize, v: bool) {
         let mask = (1 << 10) - 1;
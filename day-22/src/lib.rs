@@ -0,0 +1,132 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct Day22 {
+    hand1: VecDeque<usize>,
+    hand2: VecDeque<usize>,
+}
+
+impl Solution<'_> for Day22 {
+    const DAY: u8 = 22;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut parts = input.trim().split("\n\n");
+        let hand1 = parts.next().unwrap_or_default();
+        let hand2 = parts
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, HandParseError::MissingPlayer2))?;
+
+        let hand1 = hand1
+            .lines()
+            .skip(1)
+            .map(|l| parse_number(input, l))
+            .collect::<Result<VecDeque<usize>, _>>()?;
+        let hand2 = hand2
+            .lines()
+            .skip(1)
+            .map(|l| parse_number(input, l))
+            .collect::<Result<VecDeque<usize>, _>>()?;
+
+        Ok(Day22 { hand1, hand2 })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let (score1, score2) = game(self.hand1.clone(), self.hand2.clone());
+        Ok(score1.max(score2).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let (score1, score2) = game_rec(self.hand1.clone(), self.hand2.clone());
+        Ok(score1.max(score2).into())
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum HandParseError {
+    #[error("Missing player 2")]
+    MissingPlayer2,
+}
+
+/// Play a game of Combat, returning the final score of each player.
+pub fn game(mut hand1: VecDeque<usize>, mut hand2: VecDeque<usize>) -> (usize, usize) {
+    loop {
+        if hand1.is_empty() || hand2.is_empty() {
+            break;
+        }
+
+        let v1 = hand1.pop_front().unwrap();
+        let v2 = hand2.pop_front().unwrap();
+        if v1 > v2 {
+            hand1.push_back(v1);
+            hand1.push_back(v2);
+        } else {
+            hand2.push_back(v2);
+            hand2.push_back(v1);
+        }
+    }
+
+    (score_hand(hand1), score_hand(hand2))
+}
+
+fn score_hand(mut i: VecDeque<usize>) -> usize {
+    let mut sum = 0;
+    let mut idx = 1;
+    while let Some(v) = i.pop_back() {
+        sum += idx * v;
+        idx += 1;
+    }
+    sum
+}
+
+fn hash_hands(hand1: &VecDeque<usize>, hand2: &VecDeque<usize>) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hash;
+    use std::hash::Hasher;
+
+    let mut hasher = DefaultHasher::new();
+
+    hand1.hash(&mut hasher);
+    hand2.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+pub fn game_rec(mut hand1: VecDeque<usize>, mut hand2: VecDeque<usize>) -> (usize, usize) {
+    let mut states_seen = HashSet::new();
+
+    loop {
+        if hand1.is_empty() || hand2.is_empty() {
+            break;
+        }
+        let game_state = hash_hands(&hand1, &hand2);
+        if !states_seen.insert(game_state) {
+            // Player 1 wins.
+            return (1, 0);
+        }
+
+        let v1 = hand1.pop_front().unwrap();
+        let v2 = hand2.pop_front().unwrap();
+
+        let player1_wins = if hand1.len() >= v1 && hand2.len() >= v2 {
+            // Recurse!
+            let hand1_sub = hand1.iter().take(v1).copied().collect();
+            let hand2_sub = hand2.iter().take(v2).copied().collect();
+            let (s1, s2) = game_rec(hand1_sub, hand2_sub);
+            s1 > s2
+        } else {
+            v1 > v2
+        };
+
+        if player1_wins {
+            hand1.push_back(v1);
+            hand1.push_back(v2);
+        } else {
+            hand2.push_back(v2);
+            hand2.push_back(v1);
+        }
+    }
+
+    (score_hand(hand1), score_hand(hand2))
+}
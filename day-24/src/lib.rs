@@ -0,0 +1,297 @@
+use aoc_core::{Answer, ParseError, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day24 {
+    /// The tile reached by following each line of directions from the reference tile.
+    tiles: Vec<Tile>,
+}
+
+impl Solution<'_> for Day24 {
+    const DAY: u8 = 24;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = input
+            .trim()
+            .lines()
+            .map(|line| {
+                DirectionParser::new(line)
+                    .try_fold(Tile::reference(), |tile, dir| Ok(tile.dir(dir?)))
+                    .map_err(|err: ParseError| err.within(input, line))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Day24 { tiles })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(initial_floor(&self.tiles).count_blacks().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut floor = initial_floor(&self.tiles);
+        for _ in 0..100 {
+            floor = floor.generation();
+        }
+        Ok(floor.count_blacks().into())
+    }
+}
+
+fn initial_floor(tiles: &[Tile]) -> Floor {
+    let mut floor = Floor::default();
+    for tile in tiles {
+        floor.flip(*tile);
+    }
+    floor
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    NW,
+    NE,
+    E,
+    SE,
+    SW,
+    W,
+}
+
+#[derive(Clone, Debug)]
+pub struct DirectionParser<'a> {
+    text: &'a str,
+    input: std::str::CharIndices<'a>,
+}
+
+impl<'a> DirectionParser<'a> {
+    fn new(input: &'a str) -> Self {
+        DirectionParser {
+            text: input,
+            input: input.char_indices(),
+        }
+    }
+
+    fn error(&self, offset: usize, ch: char) -> ParseError {
+        ParseError::at_offset(self.text, offset, DirectionParseError::InvalidDirection(ch))
+    }
+}
+
+impl<'a> Iterator for DirectionParser<'a> {
+    type Item = Result<Direction, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, ch) = self.input.next()?;
+        let direction = match ch {
+            'e' => Direction::E,
+            'w' => Direction::W,
+            'n' | 's' => match (ch, self.input.next()) {
+                ('n', Some((_, 'e'))) => Direction::NE,
+                ('n', Some((_, 'w'))) => Direction::NW,
+                ('s', Some((_, 'e'))) => Direction::SE,
+                ('s', Some((_, 'w'))) => Direction::SW,
+                (_, Some((i, ch))) => return Some(Err(self.error(i, ch))),
+                (_, None) => {
+                    return Some(Err(ParseError::at_end(
+                        self.text,
+                        DirectionParseError::UnexpectedEnd,
+                    )))
+                }
+            },
+            _ => return Some(Err(self.error(i, ch))),
+        };
+        Some(Ok(direction))
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum DirectionParseError {
+    #[error("Invalid direction: {0}")]
+    InvalidDirection(char),
+    #[error("Expected \"e\" or \"w\" to finish the direction")]
+    UnexpectedEnd,
+}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Tile {
+    x: isize,
+    y: isize,
+}
+
+impl Tile {
+    fn new(x: isize, y: isize) -> Self {
+        debug_assert!((x + y) % 2 == 0);
+        Self { x, y }
+    }
+
+    pub fn reference() -> Self {
+        Tile::new(0, 0)
+    }
+
+    pub fn dir(&self, dir: Direction) -> Self {
+        use Direction::*;
+        match dir {
+            NW => Tile::new(self.x - 1, self.y - 1),
+            NE => Tile::new(self.x + 1, self.y - 1),
+            E => Tile::new(self.x + 2, self.y),
+            SE => Tile::new(self.x + 1, self.y + 1),
+            SW => Tile::new(self.x - 1, self.y + 1),
+            W => Tile::new(self.x - 2, self.y),
+        }
+    }
+
+    pub fn neighbors(&self) -> NeighborIterator {
+        NeighborIterator::new(*self)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NeighborIterator {
+    tile: Tile,
+    next: Option<Direction>,
+}
+
+impl NeighborIterator {
+    fn new(tile: Tile) -> Self {
+        NeighborIterator {
+            tile,
+            next: Some(Direction::NW),
+        }
+    }
+}
+
+impl Iterator for NeighborIterator {
+    type Item = Tile;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use Direction::*;
+        let r = self.next;
+        self.next = match r {
+            Some(NW) => Some(NE),
+            Some(NE) => Some(E),
+            Some(E) => Some(SE),
+            Some(SE) => Some(SW),
+            Some(SW) => Some(W),
+            Some(W) => None,
+            None => None,
+        };
+        r.map(|r| self.tile.dir(r))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+pub enum Color {
+    #[default]
+    White,
+    Black,
+}
+
+#[derive(Debug, Default)]
+pub struct Floor {
+    tiles: HashSet<Tile>,
+}
+
+impl Floor {
+    fn flip(&mut self, tile: Tile) {
+        if !self.tiles.insert(tile) {
+            self.tiles.remove(&tile);
+        }
+    }
+
+    fn count_blacks(&self) -> usize {
+        self.tiles.len()
+    }
+
+    fn get(&self, tile: Tile) -> Color {
+        if self.tiles.contains(&tile) {
+            Color::Black
+        } else {
+            Color::White
+        }
+    }
+
+    fn set(&mut self, tile: Tile, color: Color) {
+        if color == Color::Black {
+            self.tiles.insert(tile);
+        } else {
+            self.tiles.remove(&tile);
+        }
+    }
+
+    fn generation(&self) -> Self {
+        let mut next_day = Self::default();
+        let mut consider_tiles: HashSet<Tile> = HashSet::new();
+        for tile in &self.tiles {
+            for neighbor in tile.neighbors() {
+                consider_tiles.insert(neighbor);
+            }
+            consider_tiles.insert(*tile);
+        }
+
+        for tile in consider_tiles {
+            let mut nearby_blacks = 0;
+            for neighbor in tile.neighbors() {
+                if self.get(neighbor) == Color::Black {
+                    nearby_blacks += 1;
+                }
+            }
+            let color = match (self.get(tile), nearby_blacks) {
+                (Color::Black, 1) => Color::Black,
+                (Color::Black, 2) => Color::Black,
+                (Color::Black, _) => Color::White,
+                (Color::White, 2) => Color::Black,
+                (Color::White, _) => Color::White,
+            };
+            next_day.set(tile, color);
+        }
+        next_day
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile1() {
+        use Direction::*;
+        let t0 = Tile::reference();
+        let t1 = t0.dir(NW).dir(W).dir(SW);
+        let t2 = t0.dir(W).dir(W);
+
+        assert_eq!(t1, t2);
+    }
+
+    #[test]
+    fn parser() {
+        use Direction::*;
+        let mut parser = DirectionParser::new("esew");
+        assert_eq!(parser.next().map(Result::unwrap), Some(E));
+        assert_eq!(parser.next().map(Result::unwrap), Some(SE));
+        assert_eq!(parser.next().map(Result::unwrap), Some(W));
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn parser_invalid() {
+        let mut parser = DirectionParser::new("enx");
+        assert!(parser.next().unwrap().is_ok());
+        let err = parser.next().unwrap().unwrap_err();
+        assert_eq!(err.column(), 3);
+        assert_eq!(
+            err.kind(),
+            Some(&DirectionParseError::InvalidDirection('x'))
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        use Direction::*;
+
+        let tile = Tile::reference().dir(NW).dir(NW).dir(W).dir(NE);
+
+        let neighbors: Vec<Tile> = tile.neighbors().collect();
+        assert!(neighbors.contains(&tile.dir(NW)));
+        assert!(neighbors.contains(&tile.dir(NE)));
+        assert!(neighbors.contains(&tile.dir(E)));
+        assert!(neighbors.contains(&tile.dir(SE)));
+        assert!(neighbors.contains(&tile.dir(SW)));
+        assert!(neighbors.contains(&tile.dir(W)));
+        assert_eq!(neighbors.len(), 6);
+    }
+}
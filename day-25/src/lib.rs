@@ -0,0 +1,120 @@
+use aoc_core::{parse_number, Answer, ParseError, Result, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct PublicKeys {
+    pub card: u32,
+    pub door: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoopSizes {
+    pub card: usize,
+    pub door: usize,
+}
+
+pub struct Day25 {
+    public_keys: PublicKeys,
+}
+
+impl Day25 {
+    pub fn public_keys(&self) -> PublicKeys {
+        self.public_keys
+    }
+}
+
+impl Solution<'_> for Day25 {
+    const DAY: u8 = 25;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let mut key = |name| {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::at_end(input, KeyParseError::MissingKey(name)))?;
+            let key = parse_number(input, line)?;
+            // Every key the handshake can produce is a power of 7 modulo 20201227, which is
+            // anything in this range.
+            if key == 0 || key >= 20201227 {
+                return Err(ParseError::at(input, line, KeyParseError::OutOfRange(key)));
+            }
+            Ok(key)
+        };
+        let card = key("card")?;
+        let door = key("door")?;
+        Ok(Day25 {
+            public_keys: PublicKeys { card, door },
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let loop_sizes = loop_sizes(&self.public_keys);
+        Ok(LoopSizeCalculator::run(self.public_keys.card, loop_sizes.door).into())
+    }
+
+    /// There's no second puzzle on the last day, so this derives the encryption key from the
+    /// other side of the handshake, which had better agree with part one.
+    fn part2(&self) -> Result<Answer> {
+        let loop_sizes = loop_sizes(&self.public_keys);
+        Ok(LoopSizeCalculator::run(self.public_keys.door, loop_sizes.card).into())
+    }
+}
+
+#[derive(Debug, thiserror::Error, Clone, Copy, Eq, PartialEq)]
+pub enum KeyParseError {
+    #[error("Missing {0} public key")]
+    MissingKey(&'static str),
+    #[error("Public key {0} is out of range")]
+    OutOfRange(u32),
+}
+
+pub fn loop_sizes(public_keys: &PublicKeys) -> LoopSizes {
+    let iterator = LoopSizeCalculator::new(7);
+
+    let mut loop_size_card = None;
+    let mut loop_size_door = None;
+    for (idx, v) in iterator.enumerate() {
+        if v == public_keys.card {
+            loop_size_card = Some(idx);
+        }
+        if v == public_keys.door {
+            loop_size_door = Some(idx);
+        }
+        if loop_size_card.is_some() && loop_size_door.is_some() {
+            break;
+        }
+    }
+
+    LoopSizes {
+        card: loop_size_card.unwrap(),
+        door: loop_size_door.unwrap(),
+    }
+}
+
+pub struct LoopSizeCalculator {
+    value: u32,
+    subject: u32,
+}
+
+impl LoopSizeCalculator {
+    pub fn new(subject: u32) -> Self {
+        LoopSizeCalculator { value: 1, subject }
+    }
+
+    pub fn run(subject: u32, loop_size: usize) -> u32 {
+        let mut calc = LoopSizeCalculator::new(subject);
+        calc.nth(loop_size).unwrap()
+    }
+}
+
+impl Iterator for LoopSizeCalculator {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.value;
+        let v_64 = self.value as u64;
+        let s_64 = self.subject as u64;
+        let v_next = (v_64 * s_64) % 20201227;
+        self.value = v_next as u32;
+        Some(v)
+    }
+}
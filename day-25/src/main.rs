@@ -1,74 +1,38 @@
-use std::io::Read;
-
-fn main() {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input).unwrap();
-    let mut lines = input.lines();
-    let public_key_card = lines.next().unwrap().parse().unwrap();
-    let public_key_door = lines.next().unwrap().parse().unwrap();
-
-    let iterator = LoopSizeCalculator::new(7);
-
-    let mut loop_size_card = None;
-    let mut loop_size_door = None;
-    for (idx, v) in iterator.enumerate() {
-        if v == public_key_card {
-            loop_size_card = Some(idx);
-        }
-        if v == public_key_door {
-            loop_size_door = Some(idx);
+use aoc_core::cli::{Cli, Format};
+use aoc_core::Report;
+use day_25::Day25;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse(&[]);
+    let input = cli.read_input(Some(include_str!("../input.txt")))?;
+    let (day, mut report) = Report::solve::<Day25>(&input)?;
+
+    let public_keys = day.public_keys();
+    let loop_sizes = day_25::loop_sizes(&public_keys);
+    match cli.format() {
+        Format::Text => {
+            report.print(Format::Text)?;
+            println!(
+                "card. public_key = {}, loop_size = {}",
+                public_keys.card, loop_sizes.card
+            );
+            println!(
+                "door. public_key = {}, loop_size = {}",
+                public_keys.door, loop_sizes.door
+            );
         }
-        if loop_size_card.is_some() && loop_size_door.is_some() {
-            break;
+        Format::Json => {
+            report.extra(
+                "card",
+                serde_json::json!({ "public_key": public_keys.card, "loop_size": loop_sizes.card }),
+            )?;
+            report.extra(
+                "door",
+                serde_json::json!({ "public_key": public_keys.door, "loop_size": loop_sizes.door }),
+            )?;
+            report.print(Format::Json)?;
         }
     }
-    let loop_size_card = loop_size_card.unwrap();
-    let loop_size_door = loop_size_door.unwrap();
-
-    println!(
-        "card. public_key = {}, loop_size = {}",
-        public_key_card, loop_size_card
-    );
-    println!(
-        "door. public_key = {}, loop_size = {}",
-        public_key_door, loop_size_door
-    );
-
-    println!(
-        "encryption key = {}",
-        LoopSizeCalculator::run(public_key_card, loop_size_door)
-    );
-    println!(
-        "encryption key = {}",
-        LoopSizeCalculator::run(public_key_door, loop_size_card)
-    );
-}
-
-struct LoopSizeCalculator {
-    value: u32,
-    subject: u32,
-}
-
-impl LoopSizeCalculator {
-    fn new(subject: u32) -> Self {
-        LoopSizeCalculator { value: 1, subject }
-    }
-
-    fn run(subject: u32, loop_size: usize) -> u32 {
-        let calc = LoopSizeCalculator::new(subject);
-        calc.skip(loop_size).next().unwrap()
-    }
-}
 
-impl Iterator for LoopSizeCalculator {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let v = self.value;
-        let v_64 = self.value as u64;
-        let s_64 = self.subject as u64;
-        let v_next = (v_64 * s_64) % 20201227;
-        self.value = v_next as u32;
-        Some(v)
-    }
+    Ok(())
 }